@@ -0,0 +1,52 @@
+import { applyCounterDelta } from "../src/main/counters";
+import { CounterRecord } from "../src/shared/ipc";
+
+type Case = {
+  value: number;
+  delta: number;
+  min?: number;
+  max?: number;
+  wrap?: boolean;
+  expected: number;
+};
+
+const cases: Case[] = [
+  { value: 19, delta: 1, min: 0, max: 20, wrap: true, expected: 20 },
+  { value: 20, delta: 1, min: 0, max: 20, wrap: true, expected: 0 },
+  { value: 19, delta: 3, min: 0, max: 20, wrap: true, expected: 1 },
+  { value: 18, delta: 25, min: 0, max: 20, wrap: true, expected: 1 },
+  { value: 1, delta: -3, min: 0, max: 20, wrap: true, expected: 19 },
+  { value: 0, delta: -22, min: 0, max: 20, wrap: true, expected: 20 },
+  { value: 5, delta: 4, min: 1, max: 6, wrap: true, expected: 3 },
+  { value: 19, delta: 3, min: 0, max: 20, expected: 20 },
+  { value: 1, delta: -3, min: 0, max: 20, expected: 0 },
+  { value: 19, delta: 3, max: 20, wrap: true, expected: 20 },
+  { value: 7, delta: 3, expected: 10 }
+];
+
+const failures: string[] = [];
+
+cases.forEach((testCase, index) => {
+  const counter: CounterRecord = {
+    id: "counter",
+    label: "Counter",
+    value: testCase.value,
+    createdAt: 0,
+    ...(testCase.min !== undefined ? { min: testCase.min } : {}),
+    ...(testCase.max !== undefined ? { max: testCase.max } : {}),
+    ...(testCase.wrap ? { wrap: true } : {})
+  };
+  const { value } = applyCounterDelta(counter, testCase.delta);
+  if (value !== testCase.expected) {
+    failures.push(
+      `Case ${index + 1} (${testCase.value} + ${testCase.delta} on ${testCase.min ?? "-"}..${testCase.max ?? "-"}${testCase.wrap ? ", wrap" : ""}): expected ${testCase.expected}, got ${value}`
+    );
+  }
+});
+
+if (failures.length > 0) {
+  failures.forEach((failure) => console.error(failure));
+  process.exitCode = 1;
+} else {
+  console.log("All counter wrap checks passed.");
+}
@@ -0,0 +1,182 @@
+import {
+  ActionArgDef,
+  ActionDescriptor,
+  ActionResult,
+  CaptureStatus,
+  CounterStatus,
+  OverlaySettings
+} from "../shared/ipc";
+import type { WidgetSpec } from "../widgetSpec";
+import { globalSearch } from "./search";
+import { redoPlan, undoPlan } from "./storage";
+
+export type ActionContext = {
+  getSettings: () => Promise<OverlaySettings>;
+  applySettings: (settings: OverlaySettings) => Promise<void>;
+  publishPlan: (plan: WidgetSpec) => void;
+  stepCounter: (counterId: string, direction: 1 | -1) => Promise<CounterStatus>;
+  toggleOverlayVisibility: () => boolean;
+  getCaptureStatus: () => Promise<CaptureStatus>;
+  setCapturePaused: (paused: boolean, reason?: string) => Promise<CaptureStatus>;
+};
+
+type ActionDefinition = ActionDescriptor & {
+  run: (args: Record<string, unknown>, context: ActionContext) => Promise<unknown>;
+};
+
+const actionDefinitions: ActionDefinition[] = [
+  {
+    id: "overlay.toggleClickThrough",
+    title: "Toggle click-through",
+    description: "Switch the overlay between click-through and interactive mode.",
+    category: "overlay",
+    args: [],
+    run: async (_args, context) => {
+      const settings = await context.getSettings();
+      const next = { ...settings, clickThrough: !settings.clickThrough };
+      await context.applySettings(next);
+      return { clickThrough: next.clickThrough };
+    }
+  },
+  {
+    id: "overlay.toggleVisibility",
+    title: "Show/hide overlay",
+    description: "Hide the overlay window, or show it again if hidden.",
+    category: "overlay",
+    args: [],
+    run: async (_args, context) => ({ visible: context.toggleOverlayVisibility() })
+  },
+  {
+    id: "capture.toggle",
+    title: "Start/stop capture",
+    description: "Enable or disable the periodic OCR capture loop.",
+    category: "capture",
+    args: [],
+    run: async (_args, context) => {
+      const settings = await context.getSettings();
+      const next = { ...settings, captureEnabled: !settings.captureEnabled };
+      await context.applySettings(next);
+      return { captureEnabled: next.captureEnabled };
+    }
+  },
+  {
+    id: "capture.togglePause",
+    title: "Pause/resume capture",
+    description: "Skip captures without stopping the capture loop.",
+    category: "capture",
+    args: [{ key: "reason", label: "Pause reason", type: "string" }],
+    run: async (args, context) => {
+      const status = await context.getCaptureStatus();
+      const reason = typeof args.reason === "string" ? args.reason : undefined;
+      return { status: await context.setCapturePaused(status === "running", reason) };
+    }
+  },
+  {
+    id: "overlay.setOpacity",
+    title: "Set overlay opacity",
+    description: "Set the overlay window opacity (0.1 - 1).",
+    category: "overlay",
+    args: [{ key: "opacity", label: "Opacity", type: "number", required: true }],
+    run: async (args, context) => {
+      const opacity = Math.min(1, Math.max(0.1, args.opacity as number));
+      const settings = await context.getSettings();
+      await context.applySettings({ ...settings, opacity });
+      return { opacity };
+    }
+  },
+  {
+    id: "plan.undo",
+    title: "Undo plan change",
+    description: "Restore the previous plan snapshot.",
+    category: "plan",
+    args: [],
+    run: async (_args, context) => {
+      const plan = await undoPlan();
+      context.publishPlan(plan);
+      return plan;
+    }
+  },
+  {
+    id: "plan.redo",
+    title: "Redo plan change",
+    description: "Re-apply the next plan snapshot.",
+    category: "plan",
+    args: [],
+    run: async (_args, context) => {
+      const plan = await redoPlan();
+      context.publishPlan(plan);
+      return plan;
+    }
+  },
+  {
+    id: "counters.increment",
+    title: "Increment counter",
+    description: "Step a counter up by its configured step size.",
+    category: "counters",
+    args: [{ key: "counterId", label: "Counter", type: "string", required: true }],
+    run: async (args, context) => context.stepCounter(args.counterId as string, 1)
+  },
+  {
+    id: "counters.decrement",
+    title: "Decrement counter",
+    description: "Step a counter down by its configured step size.",
+    category: "counters",
+    args: [{ key: "counterId", label: "Counter", type: "string", required: true }],
+    run: async (args, context) => context.stepCounter(args.counterId as string, -1)
+  },
+  {
+    id: "search.global",
+    title: "Search everything",
+    description: "Search notes, timers, counters, widgets, rules, and settings.",
+    category: "search",
+    args: [{ key: "query", label: "Query", type: "string", required: true }],
+    run: async (args) => globalSearch(args.query as string)
+  }
+];
+
+const describeAction = (action: ActionDefinition): ActionDescriptor => ({
+  id: action.id,
+  title: action.title,
+  description: action.description,
+  category: action.category,
+  args: action.args
+});
+
+const validateArgs = (defs: ActionArgDef[], args: Record<string, unknown>): string | null => {
+  for (const def of defs) {
+    const value = args[def.key];
+    if (value === undefined || value === null) {
+      if (def.required) {
+        return `Missing argument "${def.key}".`;
+      }
+      continue;
+    }
+    if (typeof value !== def.type || (def.type === "number" && !Number.isFinite(value))) {
+      return `Argument "${def.key}" must be a ${def.type}.`;
+    }
+  }
+  return null;
+};
+
+export const listActions = (): ActionDescriptor[] => actionDefinitions.map(describeAction);
+
+export const executeAction = async (
+  actionId: string,
+  args: Record<string, unknown> | undefined,
+  context: ActionContext
+): Promise<ActionResult> => {
+  const action = actionDefinitions.find((candidate) => candidate.id === actionId);
+  if (!action) {
+    return { ok: false, error: `Unknown action "${actionId}".` };
+  }
+  const safeArgs = args ?? {};
+  const argsError = validateArgs(action.args, safeArgs);
+  if (argsError) {
+    return { ok: false, error: argsError };
+  }
+  try {
+    return { ok: true, result: await action.run(safeArgs, context) };
+  } catch (error: unknown) {
+    return { ok: false, error: error instanceof Error ? error.message : "Action failed." };
+  }
+};
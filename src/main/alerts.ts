@@ -0,0 +1,213 @@
+import {
+  AlertComparison,
+  AlertCondition,
+  AlertEvent,
+  AlertRule,
+  CountersStore,
+  EventLog,
+  RulesStore,
+  TimersStore,
+  UiLanguage
+} from "../shared/ipc";
+import { readGainTotal, readSittingAverage, SITTING_GAP_MS } from "../shared/gainTotals";
+import { translate } from "./i18n";
+import { logError } from "./logging";
+import { NotificationRouter } from "./notifications";
+import { loadAlertRules, loadCounters, loadEventLog, loadRules, loadTimers } from "./storage";
+
+const ALERT_TICK_MS = 5000;
+
+type MetricSources = {
+  eventLog: EventLog;
+  counters: CountersStore;
+  timers: TimersStore;
+  rules: RulesStore;
+};
+
+type AlertRuntimeState = {
+  active: boolean;
+  lastFiredAt?: number;
+  matchingSince?: number;
+};
+
+const runtimeState = new Map<string, AlertRuntimeState>();
+let tickTimer: NodeJS.Timeout | null = null;
+let tickInFlight = false;
+
+const compare = (value: number, op: AlertComparison, threshold: number) => {
+  switch (op) {
+    case "lt":
+      return value < threshold;
+    case "lte":
+      return value <= threshold;
+    case "gt":
+      return value > threshold;
+    case "gte":
+      return value >= threshold;
+  }
+};
+
+export const readMetric = (
+  condition: AlertCondition,
+  sources: MetricSources,
+  now: number
+): number | null => {
+  switch (condition.metric) {
+    case "counterValue": {
+      const counter = sources.counters.counters.find((item) => item.id === condition.counterId);
+      return counter ? counter.value : null;
+    }
+    case "eventRate": {
+      const since = now - condition.windowMinutes * 60 * 1000;
+      const count = sources.eventLog.entries.filter(
+        (entry) => entry.eventType === condition.eventType && entry.timestamp >= since
+      ).length;
+      return count / (condition.windowMinutes / 60);
+    }
+    case "secondsSinceEvent": {
+      const latest = sources.eventLog.entries
+        .filter((entry) => entry.eventType === condition.eventType)
+        .reduce((max, entry) => Math.max(max, entry.timestamp), 0);
+      return latest > 0 ? (now - latest) / 1000 : null;
+    }
+    case "timerRemainingSeconds": {
+      const timer = sources.timers.timers.find((item) => item.id === condition.timerId);
+      return timer ? Math.max(0, (timer.endsAt - now) / 1000) : null;
+    }
+    case "trackRate": {
+      // The per-hour rate the rule last displayed. A rule that has not seen a
+      // sample for a whole sitting gap has no current rate to compare.
+      const rule = sources.rules.rules.find((item) => item.id === condition.ruleId);
+      const state = rule?.state;
+      if (state?.lastRate === undefined || state.lastAt === undefined) {
+        return null;
+      }
+      return now - state.lastAt < SITTING_GAP_MS ? state.lastRate : null;
+    }
+    case "trackRatePercentOfAverage": {
+      // "Below 60% of this sitting's average" catches drops (kill-stealers,
+      // expired buffs) without a fixed threshold per grinding spot.
+      const rule = sources.rules.rules.find((item) => item.id === condition.ruleId);
+      const state = rule?.state;
+      const average = readSittingAverage(state, now);
+      if (average === null || average <= 0 || state?.lastRate === undefined) {
+        return null;
+      }
+      return (state.lastRate / average) * 100;
+    }
+    case "rateGained": {
+      // Gains are in the trackRate rule's own units: percent for a percent
+      // readout, absolute exp for a raw total or an exp-table conversion.
+      const rule = sources.rules.rules.find((item) => item.id === condition.ruleId);
+      return rule ? readGainTotal(rule.state, condition.scope, now) : null;
+    }
+  }
+};
+
+const describeAlert = (rule: AlertRule, value: number, language: UiLanguage) =>
+  translate(language, "alerts.fired", {
+    name: rule.name,
+    metric: rule.condition.metric,
+    op: rule.condition.op,
+    threshold: rule.condition.value,
+    value
+  });
+
+// Alerts are edge-triggered: a rule fires when its condition turns true and
+// must turn false again before it can re-fire, subject to its cooldown.
+export const evaluateAlerts = async (
+  now: number,
+  language: UiLanguage
+): Promise<Array<[AlertEvent, AlertRule]>> => {
+  const [store, eventLog, counters, timers, rules] = await Promise.all([
+    loadAlertRules(),
+    loadEventLog(),
+    loadCounters(),
+    loadTimers(),
+    loadRules()
+  ]);
+  const sources: MetricSources = { eventLog, counters, timers, rules };
+  const fired: Array<[AlertEvent, AlertRule]> = [];
+  const knownIds = new Set<string>();
+  store.rules.forEach((rule) => {
+    knownIds.add(rule.id);
+    if (!rule.enabled) {
+      runtimeState.delete(rule.id);
+      return;
+    }
+    const state = runtimeState.get(rule.id) ?? { active: false };
+    const value = readMetric(rule.condition, sources, now);
+    const matches = value !== null && compare(value, rule.condition.op, rule.condition.value);
+    if (!matches || value === null) {
+      runtimeState.set(rule.id, { active: false, lastFiredAt: state.lastFiredAt });
+      return;
+    }
+    // A sustained rule must hold for the whole window before it counts as
+    // true, so a single slow interval does not fire a rate-drop alert.
+    const matchingSince = state.matchingSince ?? now;
+    if (now - matchingSince < (rule.sustainSeconds ?? 0) * 1000) {
+      runtimeState.set(rule.id, { ...state, matchingSince });
+      return;
+    }
+    const cooldownMs = (rule.cooldownSeconds ?? 0) * 1000;
+    const coolingDown = state.lastFiredAt !== undefined && now - state.lastFiredAt < cooldownMs;
+    if (state.active || coolingDown) {
+      runtimeState.set(rule.id, { ...state, active: true, matchingSince });
+      return;
+    }
+    runtimeState.set(rule.id, { active: true, lastFiredAt: now, matchingSince });
+    fired.push([
+      {
+        ruleId: rule.id,
+        name: rule.name,
+        critical: Boolean(rule.critical),
+        metricValue: value,
+        message: describeAlert(rule, value, language),
+        firedAt: now
+      },
+      rule
+    ]);
+  });
+  [...runtimeState.keys()].forEach((id) => {
+    if (!knownIds.has(id)) {
+      runtimeState.delete(id);
+    }
+  });
+  return fired;
+};
+
+export const startAlertEngine = (
+  router: NotificationRouter,
+  getLanguage: () => Promise<UiLanguage>
+) => {
+  if (tickTimer) {
+    return;
+  }
+  tickTimer = setInterval(() => {
+    if (tickInFlight) {
+      return;
+    }
+    tickInFlight = true;
+    getLanguage()
+      .then((language) => evaluateAlerts(Date.now(), language))
+      .then((fired) =>
+        Promise.all(fired.map(([event, rule]) => router.deliver(event, rule.actions)))
+      )
+      .catch((error: unknown) =>
+        logError("alerts.tick.failed", {
+          error: error instanceof Error ? error.message : String(error)
+        })
+      )
+      .finally(() => {
+        tickInFlight = false;
+      });
+  }, ALERT_TICK_MS);
+};
+
+export const stopAlertEngine = () => {
+  if (tickTimer) {
+    clearInterval(tickTimer);
+    tickTimer = null;
+  }
+  runtimeState.clear();
+};
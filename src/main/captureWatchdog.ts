@@ -0,0 +1,49 @@
+import { AlertAction, AlertEvent, CaptureWatchdogSettings, UiLanguage } from "../shared/ipc";
+import { translate } from "./i18n";
+import { logInfo } from "./logging";
+import { NotificationRouter } from "./notifications";
+
+const STALLED_RULE_ID = "capture-stalled";
+const STALLED_ACTIONS: AlertAction[] = [{ type: "toast" }, { type: "sound" }];
+
+let consecutiveEmpty = 0;
+let alerted = false;
+
+export const resetCaptureWatchdog = () => {
+  consecutiveEmpty = 0;
+  alerted = false;
+};
+
+// Counts captures that yielded no text (OCR window moved, game minimised,
+// OCR failing) and raises a single critical alert once the run reaches the
+// configured length. Any capture with text re-arms it.
+export const recordCaptureResult = async (
+  settings: CaptureWatchdogSettings,
+  producedText: boolean,
+  router: NotificationRouter,
+  language: UiLanguage,
+  now = Date.now()
+) => {
+  if (producedText) {
+    if (alerted) {
+      await logInfo("capture.watchdog.recovered", { emptyCaptures: consecutiveEmpty });
+    }
+    resetCaptureWatchdog();
+    return;
+  }
+  consecutiveEmpty += 1;
+  if (!settings.enabled || alerted || consecutiveEmpty < settings.stalledAfter) {
+    return;
+  }
+  alerted = true;
+  const event: AlertEvent = {
+    ruleId: STALLED_RULE_ID,
+    name: translate(language, "watchdog.stalled.title"),
+    critical: true,
+    metricValue: consecutiveEmpty,
+    message: translate(language, "watchdog.stalled.message", { count: consecutiveEmpty }),
+    firedAt: now
+  };
+  await logInfo("capture.watchdog.stalled", { emptyCaptures: consecutiveEmpty });
+  await router.deliver(event, STALLED_ACTIONS);
+};
@@ -0,0 +1,103 @@
+import { execFile } from "child_process";
+import {
+  CompatibilityFinding,
+  CompatibilityReport,
+  CompatibilitySuggestion,
+  UiLanguage
+} from "../shared/ipc";
+import { MessageKey, translate } from "./i18n";
+
+type KnownProcess = Omit<CompatibilityFinding, "processName"> & { match: RegExp };
+
+// Matched against image names from the process list. Anti-cheat drivers are
+// the real risk: some treat screen capture and window enumeration from a
+// foreign process as suspicious. Other overlays only compete for the top-most
+// layer and hotkeys.
+const KNOWN_PROCESSES: KnownProcess[] = [
+  { match: /^easyanticheat(_eos)?\.exe$/, label: "Easy Anti-Cheat", kind: "anticheat" },
+  { match: /^beservice(_x64)?\.exe$/, label: "BattlEye", kind: "anticheat" },
+  { match: /^vgc\.exe$/, label: "Riot Vanguard", kind: "anticheat" },
+  { match: /^gamemon(64)?\.(exe|des)$/, label: "nProtect GameGuard", kind: "anticheat" },
+  { match: /^xigncode|^x3\.xem$/, label: "XIGNCODE3", kind: "anticheat" },
+  { match: /^sguard(64)?\.exe$|^ace-tray\.exe$/, label: "Tencent ACE", kind: "anticheat" },
+  { match: /^gamebar(ftserver)?\.exe$/, label: "Xbox Game Bar", kind: "overlay" },
+  { match: /^gameoverlayui\.exe$/, label: "Steam overlay", kind: "overlay" },
+  { match: /^discordhookhelper(64)?\.exe$/, label: "Discord overlay", kind: "overlay" },
+  { match: /^overwolf\.exe$/, label: "Overwolf", kind: "overlay" },
+  { match: /^rtss\.exe$/, label: "RivaTuner Statistics Server", kind: "overlay" },
+  { match: /^nvidia share\.exe$/, label: "NVIDIA overlay", kind: "overlay" }
+];
+
+const SUGGESTIONS: Record<CompatibilitySuggestion["id"], MessageKey> = {
+  disableCapture: "compat.disableCapture",
+  disableWindowCapture: "compat.disableWindowCapture",
+  enableClickThrough: "compat.enableClickThrough"
+};
+
+// The process scan is cached, not the report, so suggestions follow the
+// current language. A null process list means detection is unsupported.
+let cachedScan: { checkedAt: number; processNames: string[] | null } | null = null;
+
+const listProcessNames = (): Promise<string[]> =>
+  new Promise((resolve, reject) => {
+    execFile(
+      "tasklist.exe",
+      ["/fo", "csv", "/nh"],
+      { windowsHide: true, maxBuffer: 4 * 1024 * 1024 },
+      (error, stdout) => {
+        if (error) {
+          reject(error);
+          return;
+        }
+        resolve(
+          stdout
+            .split(/\r?\n/)
+            .map((line) => /^"([^"]+)"/.exec(line)?.[1]?.toLowerCase())
+            .filter((name): name is string => Boolean(name))
+        );
+      }
+    );
+  });
+
+export const buildCompatibilityReport = (
+  processNames: string[],
+  language: UiLanguage,
+  now = Date.now()
+): CompatibilityReport => {
+  const findings: CompatibilityFinding[] = [];
+  KNOWN_PROCESSES.forEach(({ match, label, kind }) => {
+    const processName = processNames.find((name) => match.test(name));
+    if (processName && !findings.some((finding) => finding.label === label)) {
+      findings.push({ processName, label, kind });
+    }
+  });
+  const ids: CompatibilitySuggestion["id"][] = [];
+  if (findings.some((finding) => finding.kind === "anticheat")) {
+    ids.push("disableCapture", "disableWindowCapture");
+  }
+  if (findings.some((finding) => finding.kind === "overlay")) {
+    ids.push("enableClickThrough");
+  }
+  return {
+    checkedAt: now,
+    supported: true,
+    findings,
+    suggestions: ids.map((id) => ({ id, description: translate(language, SUGGESTIONS[id]) }))
+  };
+};
+
+// Process detection only works on Windows; elsewhere the report says so
+// rather than claiming the environment is clean.
+export const checkCompatibility = async (
+  language: UiLanguage,
+  refresh = false
+): Promise<CompatibilityReport> => {
+  if (!cachedScan || refresh) {
+    const processNames = process.platform === "win32" ? await listProcessNames() : null;
+    cachedScan = { checkedAt: Date.now(), processNames };
+  }
+  if (cachedScan.processNames === null) {
+    return { checkedAt: cachedScan.checkedAt, supported: false, findings: [], suggestions: [] };
+  }
+  return buildCompatibilityReport(cachedScan.processNames, language, cachedScan.checkedAt);
+};
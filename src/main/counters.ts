@@ -0,0 +1,109 @@
+import {
+  CounterEvent,
+  CounterGoalEvent,
+  CounterRecord,
+  CounterStatus,
+  UiLanguage
+} from "../shared/ipc";
+import { translate } from "./i18n";
+
+export const getCounterStatus = (counter: CounterRecord): CounterStatus => {
+  if (counter.target === undefined || counter.target <= 0) {
+    return { ...counter, progressPercent: null, completed: false };
+  }
+  const progress = (counter.value / counter.target) * 100;
+  return {
+    ...counter,
+    progressPercent: Math.min(100, Math.max(0, progress)),
+    completed: counter.value >= counter.target
+  };
+};
+
+// Wraparound only applies when both bounds are set and treats min..max as a
+// ring, so the overflow carries over (19 + 3 on 0..20 gives 1). Otherwise the
+// value clamps to whichever bound it crossed.
+export const applyCounterDelta = (counter: CounterRecord, delta: number): CounterRecord => {
+  const next = counter.value + delta;
+  const { min, max } = counter;
+  if (counter.wrap && min !== undefined && max !== undefined) {
+    const span = max - min + 1;
+    return { ...counter, value: min + ((((next - min) % span) + span) % span) };
+  }
+  if (max !== undefined && next > max) {
+    return { ...counter, value: max };
+  }
+  if (min !== undefined && next < min) {
+    return { ...counter, value: min };
+  }
+  return { ...counter, value: next };
+};
+
+export const applyCounterStep = (counter: CounterRecord, direction: 1 | -1): CounterRecord =>
+  applyCounterDelta(counter, (counter.step ?? 1) * direction);
+
+export const setCounterValue = (counter: CounterRecord, value: number): CounterRecord => {
+  const floored = counter.min !== undefined ? Math.max(counter.min, value) : value;
+  return {
+    ...counter,
+    value: counter.max !== undefined ? Math.min(counter.max, floored) : floored
+  };
+};
+
+export const resetCounterValue = (counter: CounterRecord): CounterRecord => {
+  const floor = counter.min !== undefined ? Math.max(counter.min, 0) : 0;
+  return {
+    ...counter,
+    value: counter.max !== undefined ? Math.min(counter.max, floor) : floor
+  };
+};
+
+export const diffCounterValues = (
+  previous: CounterRecord[],
+  next: CounterRecord[],
+  timestamp: number
+): CounterEvent[] => {
+  const previousById = new Map(previous.map((counter) => [counter.id, counter]));
+  return next
+    .map((counter) => {
+      const before = previousById.get(counter.id);
+      const delta = counter.value - (before?.value ?? 0);
+      if (before && delta === 0) {
+        return null;
+      }
+      return { counterId: counter.id, value: counter.value, delta, timestamp };
+    })
+    .filter((event): event is CounterEvent => event !== null);
+};
+
+// A goal is reached only on the transition across the target, so re-saving a
+// completed counter does not emit the event again.
+export const findReachedGoals = (
+  previous: CounterRecord[],
+  next: CounterRecord[],
+  reachedAt: number,
+  language: UiLanguage
+): CounterGoalEvent[] => {
+  const previousById = new Map(previous.map((counter) => [counter.id, counter]));
+  const events: CounterGoalEvent[] = [];
+  next.forEach((counter) => {
+    if (counter.target === undefined || counter.value < counter.target) {
+      return;
+    }
+    const before = previousById.get(counter.id);
+    if (before && before.value >= counter.target) {
+      return;
+    }
+    events.push({
+      counterId: counter.id,
+      label: counter.label,
+      value: counter.value,
+      target: counter.target,
+      reachedAt,
+      message: translate(language, "counters.goalReached", {
+        label: counter.label,
+        target: counter.target
+      })
+    });
+  });
+  return events;
+};
@@ -0,0 +1,93 @@
+import { createWriteStream, promises as fs } from "fs";
+import { isAbsolute } from "path";
+import { once } from "events";
+import { CounterEvent, CsvExportResult, EventLogEntry } from "../shared/ipc";
+
+export type CsvCell = string | number | null | undefined;
+
+const escapeCell = (value: CsvCell) => {
+  if (value === null || value === undefined) {
+    return "";
+  }
+  const text = String(value);
+  return /[",\r\n]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text;
+};
+
+const toCsvLine = (cells: CsvCell[]) => `${cells.map(escapeCell).join(",")}\r\n`;
+
+// Rows are written as they are produced so large histories never build one
+// big string in memory; backpressure is honoured via the drain event.
+export const writeCsv = async (
+  path: string,
+  header: string[],
+  rows: Iterable<CsvCell[]>
+): Promise<CsvExportResult> => {
+  if (!isAbsolute(path)) {
+    throw new Error("Export path must be absolute.");
+  }
+  const stream = createWriteStream(path, { encoding: "utf8" });
+  let rowCount = 0;
+  try {
+    stream.write(toCsvLine(header));
+    for (const row of rows) {
+      if (!stream.write(toCsvLine(row))) {
+        await once(stream, "drain");
+      }
+      rowCount += 1;
+    }
+  } finally {
+    stream.end();
+    await once(stream, "close");
+  }
+  return { path, rowCount };
+};
+
+export const writeJsonRows = async (
+  path: string,
+  header: string[],
+  rows: Iterable<CsvCell[]>
+): Promise<CsvExportResult> => {
+  if (!isAbsolute(path)) {
+    throw new Error("Export path must be absolute.");
+  }
+  const records = Array.from(rows, (row) =>
+    Object.fromEntries(header.map((key, index) => [key, row[index] ?? null]))
+  );
+  await fs.writeFile(path, JSON.stringify(records, null, 2), "utf8");
+  return { path, rowCount: records.length };
+};
+
+export const isoTime = (timestamp: number) => new Date(timestamp).toISOString();
+
+export const exportCounterHistoryCsv = (
+  path: string,
+  events: CounterEvent[],
+  counterNames: Map<string, string>
+) =>
+  writeCsv(
+    path,
+    ["timestamp", "time", "counter_id", "counter_label", "value", "delta"],
+    events.map((event) => [
+      event.timestamp,
+      isoTime(event.timestamp),
+      event.counterId,
+      counterNames.get(event.counterId) ?? "",
+      event.value,
+      event.delta
+    ])
+  );
+
+export const exportEventLogCsv = (path: string, entries: EventLogEntry[]) =>
+  writeCsv(
+    path,
+    ["timestamp", "time", "event_type", "note", "text", "confidence", "source"],
+    entries.map((entry) => [
+      entry.timestamp,
+      isoTime(entry.timestamp),
+      entry.eventType,
+      entry.note,
+      entry.data?.text,
+      entry.data?.confidence,
+      entry.data?.sourceName
+    ])
+  );
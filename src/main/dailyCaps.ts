@@ -0,0 +1,116 @@
+import {
+  AlertEvent,
+  DailyCap,
+  DailyCapStatus,
+  EventLog,
+  RulesStore,
+  UiLanguage
+} from "../shared/ipc";
+import { localDayKey, readGainTotal } from "../shared/gainTotals";
+import { translate } from "./i18n";
+import { logError } from "./logging";
+import { NotificationRouter } from "./notifications";
+import { loadDailyCaps, loadEventLog, loadRules } from "./storage";
+
+const CAP_TICK_MS = 30 * 1000;
+
+type WarningLevel = "near" | "reached";
+
+// capId -> the highest warning already sent and the local day it was sent on.
+const warnedOn = new Map<string, { day: string; level: WarningLevel }>();
+let capTimer: NodeJS.Timeout | null = null;
+
+const readUsage = (cap: DailyCap, rules: RulesStore, eventLog: EventLog, now: number) => {
+  if (cap.source.type === "rateRule") {
+    const ruleId = cap.source.ruleId;
+    const rule = rules.rules.find((item) => item.id === ruleId);
+    return readGainTotal(rule?.state, "today", now);
+  }
+  const eventType = cap.source.eventType;
+  const today = localDayKey(now);
+  return eventLog.entries.filter(
+    (entry) => entry.eventType === eventType && localDayKey(entry.timestamp) === today
+  ).length;
+};
+
+export const getDailyCapStatuses = async (now = Date.now()): Promise<DailyCapStatus[]> => {
+  const [store, rules, eventLog] = await Promise.all([
+    loadDailyCaps(),
+    loadRules(),
+    loadEventLog()
+  ]);
+  return store.caps.map((cap) => {
+    const used = readUsage(cap, rules, eventLog, now);
+    const percentUsed = (used / cap.limit) * 100;
+    return {
+      capId: cap.id,
+      label: cap.label,
+      day: localDayKey(now),
+      used,
+      limit: cap.limit,
+      remaining: Math.max(0, cap.limit - used),
+      percentUsed,
+      nearCap: percentUsed >= cap.warnAtPercent
+    };
+  });
+};
+
+// Each cap warns at most once per level per local day: once when usage
+// crosses its threshold and again, as critical, when the cap is reached.
+const checkCaps = async (router: NotificationRouter, language: UiLanguage) => {
+  const now = Date.now();
+  const statuses = await getDailyCapStatuses(now);
+  for (const status of statuses) {
+    if (!status.nearCap) {
+      continue;
+    }
+    const reached = status.remaining <= 0;
+    const level: WarningLevel = reached ? "reached" : "near";
+    const previous = warnedOn.get(status.capId);
+    if (previous?.day === status.day && (previous.level === "reached" || !reached)) {
+      continue;
+    }
+    warnedOn.set(status.capId, { day: status.day, level });
+    const event: AlertEvent = {
+      ruleId: `daily-cap:${status.capId}`,
+      name: status.label,
+      critical: reached,
+      metricValue: status.used,
+      message: reached
+        ? translate(language, "caps.reached", { label: status.label, limit: status.limit })
+        : translate(language, "caps.near", {
+            label: status.label,
+            percent: Math.round(status.percentUsed),
+            remaining: status.remaining
+          }),
+      firedAt: now
+    };
+    await router.deliver(event, [{ type: "toast" }, { type: "sound" }]);
+  }
+};
+
+export const startDailyCapMonitor = (
+  router: NotificationRouter,
+  getLanguage: () => Promise<UiLanguage>
+) => {
+  if (capTimer) {
+    return;
+  }
+  capTimer = setInterval(() => {
+    getLanguage()
+      .then((language) => checkCaps(router, language))
+      .catch((error: unknown) =>
+        logError("dailyCaps.check.failed", {
+          error: error instanceof Error ? error.message : String(error)
+        })
+      );
+  }, CAP_TICK_MS);
+};
+
+export const stopDailyCapMonitor = () => {
+  if (capTimer) {
+    clearInterval(capTimer);
+    capTimer = null;
+  }
+  warnedOn.clear();
+};
@@ -0,0 +1,80 @@
+import {
+  EventLog,
+  ExperimentCompareOptions,
+  ExperimentComparison,
+  ExperimentMarker,
+  ExperimentWindowStats
+} from "../shared/ipc";
+
+const SIGNIFICANCE_LEVEL = 0.05;
+
+// Abramowitz-Stegun 7.1.26; plenty for a p-value shown to two decimals.
+const erf = (x: number) => {
+  const sign = x < 0 ? -1 : 1;
+  const t = 1 / (1 + 0.3275911 * Math.abs(x));
+  const poly =
+    t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+  return sign * (1 - poly * Math.exp(-x * x));
+};
+
+const twoSidedPValue = (z: number) => 1 - erf(Math.abs(z) / Math.SQRT2);
+
+const countWindow = (
+  log: EventLog,
+  eventType: string,
+  start: number,
+  end: number
+): ExperimentWindowStats => {
+  const count = log.entries.filter(
+    (entry) => entry.eventType === eventType && entry.timestamp >= start && entry.timestamp < end
+  ).length;
+  const minutes = Math.max(0, (end - start) / 60000);
+  return {
+    start,
+    end,
+    count,
+    ratePerHour: minutes > 0 ? count / (minutes / 60) : 0
+  };
+};
+
+// Treats both windows as Poisson processes and tests for a common rate. The
+// "after" window is clipped to `now`, so a fresh marker compares a shorter
+// span rather than counting events that have not happened yet.
+export const compareExperiment = (
+  marker: ExperimentMarker,
+  log: EventLog,
+  options: ExperimentCompareOptions,
+  now = Date.now()
+): ExperimentComparison => {
+  const windowMs = options.windowMinutes * 60000;
+  const before = countWindow(log, options.eventType, marker.markedAt - windowMs, marker.markedAt);
+  const after = countWindow(
+    log,
+    options.eventType,
+    marker.markedAt,
+    Math.min(now, marker.markedAt + windowMs)
+  );
+  const beforeHours = (before.end - before.start) / 3600000;
+  const afterHours = (after.end - after.start) / 3600000;
+  const pooledRate =
+    beforeHours + afterHours > 0 ? (before.count + after.count) / (beforeHours + afterHours) : 0;
+  const variance =
+    beforeHours > 0 && afterHours > 0 ? pooledRate / beforeHours + pooledRate / afterHours : 0;
+  const zScore = variance > 0 ? (after.ratePerHour - before.ratePerHour) / Math.sqrt(variance) : null;
+  const pValue = zScore === null ? null : twoSidedPValue(zScore);
+  return {
+    label: marker.label,
+    markedAt: marker.markedAt,
+    eventType: options.eventType,
+    windowMinutes: options.windowMinutes,
+    before,
+    after,
+    changePercent:
+      before.ratePerHour > 0
+        ? ((after.ratePerHour - before.ratePerHour) / before.ratePerHour) * 100
+        : null,
+    zScore,
+    pValue,
+    significant: pValue !== null && pValue < SIGNIFICANCE_LEVEL
+  };
+};
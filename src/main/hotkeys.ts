@@ -0,0 +1,41 @@
+import { globalShortcut } from "electron";
+import { HotkeyBinding, HotkeyStatus } from "../shared/ipc";
+
+const registeredAccelerators = new Set<string>();
+let lastStatuses: HotkeyStatus[] = [];
+
+export const unregisterHotkeys = () => {
+  registeredAccelerators.forEach((accelerator) => globalShortcut.unregister(accelerator));
+  registeredAccelerators.clear();
+};
+
+// Re-registers every enabled binding from scratch. A binding that the OS or
+// another app already owns is reported rather than failing the whole set.
+export const registerHotkeys = (
+  bindings: HotkeyBinding[],
+  trigger: (binding: HotkeyBinding) => void
+): HotkeyStatus[] => {
+  unregisterHotkeys();
+  lastStatuses = bindings.map((binding) => {
+    if (!binding.enabled) {
+      return { ...binding, registered: false };
+    }
+    try {
+      const registered = globalShortcut.register(binding.accelerator, () => trigger(binding));
+      if (!registered) {
+        return { ...binding, registered: false, error: "Shortcut is already in use." };
+      }
+      registeredAccelerators.add(binding.accelerator);
+      return { ...binding, registered: true };
+    } catch (error) {
+      return {
+        ...binding,
+        registered: false,
+        error: error instanceof Error ? error.message : "Invalid accelerator."
+      };
+    }
+  });
+  return lastStatuses;
+};
+
+export const getHotkeyStatuses = () => lastStatuses;
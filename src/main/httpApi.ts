@@ -0,0 +1,282 @@
+import { createServer, IncomingMessage, Server, ServerResponse } from "http";
+import { timingSafeEqual } from "crypto";
+import {
+  CaptureStatus,
+  CounterStatus,
+  DailyCapStatus,
+  EventLog,
+  HttpApiSettings,
+  RateReading,
+  RateSample,
+  TimerCreateInput,
+  TimerListResult,
+  TimerRecord
+} from "../shared/ipc";
+import type { WidgetSpec } from "../widgetSpec";
+import { logError, logInfo } from "./logging";
+
+const BODY_LIMIT_BYTES = 16 * 1024;
+
+export type HttpApiHandlers = {
+  incrementCounter: (counterId: string, delta: number) => Promise<CounterStatus>;
+  createTimer: (input: TimerCreateInput) => Promise<TimerRecord>;
+  getStatus: () => Promise<{ capture: CaptureStatus; at: number }>;
+  listRates: () => Promise<RateReading[]>;
+  listRateSamples: (ruleId: string) => Promise<RateSample[]>;
+  listCounters: () => Promise<CounterStatus[]>;
+  listTimers: () => Promise<TimerListResult>;
+  listDailyCaps: () => Promise<DailyCapStatus[]>;
+  getPlan: () => Promise<WidgetSpec | null>;
+  getEventLog: () => Promise<EventLog>;
+};
+
+type Route = {
+  method: string;
+  pattern: RegExp;
+  requiresToken: boolean;
+  run: (params: string[], body: unknown, handlers: HttpApiHandlers) => Promise<unknown>;
+};
+
+class HttpError extends Error {
+  constructor(
+    readonly status: number,
+    message: string
+  ) {
+    super(message);
+  }
+}
+
+const routes: Route[] = [
+  {
+    method: "GET",
+    pattern: /^\/status$/,
+    requiresToken: false,
+    run: (_params, _body, handlers) => handlers.getStatus()
+  },
+  {
+    method: "GET",
+    pattern: /^\/rates$/,
+    requiresToken: false,
+    run: (_params, _body, handlers) => handlers.listRates()
+  },
+  {
+    method: "GET",
+    pattern: /^\/rates\/([^/]+)\/samples$/,
+    requiresToken: false,
+    run: ([ruleId], _body, handlers) => handlers.listRateSamples(decodeURIComponent(ruleId))
+  },
+  {
+    method: "GET",
+    pattern: /^\/counters$/,
+    requiresToken: false,
+    run: (_params, _body, handlers) => handlers.listCounters()
+  },
+  {
+    method: "GET",
+    pattern: /^\/timers$/,
+    requiresToken: false,
+    run: (_params, _body, handlers) => handlers.listTimers()
+  },
+  {
+    method: "GET",
+    pattern: /^\/caps$/,
+    requiresToken: false,
+    run: (_params, _body, handlers) => handlers.listDailyCaps()
+  },
+  {
+    method: "GET",
+    pattern: /^\/plan$/,
+    requiresToken: true,
+    run: (_params, _body, handlers) => handlers.getPlan()
+  },
+  {
+    method: "GET",
+    pattern: /^\/event-log$/,
+    requiresToken: true,
+    run: (_params, _body, handlers) => handlers.getEventLog()
+  },
+  {
+    method: "POST",
+    pattern: /^\/counters\/([^/]+)\/increment$/,
+    requiresToken: true,
+    run: async ([counterId], body, handlers) => {
+      const delta = (body as { delta?: unknown } | null)?.delta ?? 1;
+      if (typeof delta !== "number" || !Number.isFinite(delta)) {
+        throw new HttpError(400, "delta must be a number.");
+      }
+      return handlers.incrementCounter(decodeURIComponent(counterId), delta);
+    }
+  },
+  {
+    method: "POST",
+    pattern: /^\/timers$/,
+    requiresToken: true,
+    run: async (_params, body, handlers) => {
+      const input = body as Partial<TimerCreateInput> | null;
+      if (typeof input?.label !== "string" || typeof input.durationSeconds !== "number") {
+        throw new HttpError(400, "label and durationSeconds are required.");
+      }
+      return handlers.createTimer({
+        label: input.label,
+        durationSeconds: input.durationSeconds,
+        ...(typeof input.category === "string" ? { category: input.category } : {}),
+        ...(typeof input.color === "string" ? { color: input.color } : {})
+      });
+    }
+  }
+];
+
+let server: Server | null = null;
+let activeSettings: HttpApiSettings | null = null;
+
+const sendJson = (response: ServerResponse, status: number, payload: unknown) => {
+  response.writeHead(status, { "Content-Type": "application/json; charset=utf-8" });
+  response.end(JSON.stringify(payload));
+};
+
+// Storage reports a missing timer, counter or table as a plain "... not found."
+// error, so those map to 404; anything else the handlers reject is a bad request.
+const errorStatus = (error: unknown) => {
+  if (error instanceof HttpError) {
+    return error.status;
+  }
+  return error instanceof Error && /\bnot found\b/i.test(error.message) ? 404 : 400;
+};
+
+// Browser sources (OBS, a dashboard tab on a second PC) only get CORS headers
+// when their origin is listed in settings; any other page cannot read replies.
+const applyCors = (
+  request: IncomingMessage,
+  response: ServerResponse,
+  settings: HttpApiSettings
+) => {
+  const origin = request.headers.origin;
+  if (!origin || !settings.allowedOrigins.includes(origin)) {
+    return false;
+  }
+  response.setHeader("Access-Control-Allow-Origin", origin);
+  response.setHeader("Access-Control-Allow-Headers", "Authorization, Content-Type");
+  response.setHeader("Access-Control-Allow-Methods", "GET, POST");
+  response.setHeader("Vary", "Origin");
+  return true;
+};
+
+const readBody = (request: IncomingMessage): Promise<unknown> =>
+  new Promise((resolve, reject) => {
+    let size = 0;
+    const chunks: Buffer[] = [];
+    request.on("data", (chunk: Buffer) => {
+      size += chunk.length;
+      if (size > BODY_LIMIT_BYTES) {
+        reject(new HttpError(413, "Request body too large."));
+        request.destroy();
+        return;
+      }
+      chunks.push(chunk);
+    });
+    request.on("end", () => {
+      const text = Buffer.concat(chunks).toString("utf8").trim();
+      try {
+        resolve(text ? JSON.parse(text) : null);
+      } catch {
+        reject(new HttpError(400, "Body must be JSON."));
+      }
+    });
+    request.on("error", reject);
+  });
+
+// Writes, the plan and the event log (OCR text, capture paths) need
+// `Authorization: Bearer <token>`; with no token configured they stay
+// disabled rather than open to the whole LAN.
+const isAuthorized = (request: IncomingMessage, token: string) => {
+  if (!token) {
+    return false;
+  }
+  const header = request.headers.authorization ?? "";
+  const supplied = Buffer.from(header.replace(/^Bearer\s+/i, ""));
+  const expected = Buffer.from(token);
+  return supplied.length === expected.length && timingSafeEqual(supplied, expected);
+};
+
+const handleRequest = async (
+  request: IncomingMessage,
+  response: ServerResponse,
+  settings: HttpApiSettings,
+  handlers: HttpApiHandlers
+) => {
+  const path = new URL(request.url ?? "/", "http://localhost").pathname;
+  const corsAllowed = applyCors(request, response, settings);
+  if (request.method === "OPTIONS") {
+    response.writeHead(corsAllowed ? 204 : 403);
+    response.end();
+    return;
+  }
+  const route = routes.find(
+    (candidate) => candidate.method === request.method && candidate.pattern.test(path)
+  );
+  if (!route) {
+    sendJson(response, 404, { error: "Not found." });
+    return;
+  }
+  if (route.requiresToken && !isAuthorized(request, settings.token)) {
+    sendJson(response, 401, { error: "Missing or invalid token." });
+    return;
+  }
+  try {
+    const params = route.pattern.exec(path)?.slice(1) ?? [];
+    const body = request.method === "GET" ? null : await readBody(request);
+    sendJson(response, 200, await route.run(params, body, handlers));
+  } catch (error: unknown) {
+    sendJson(response, errorStatus(error), {
+      error: error instanceof Error ? error.message : "Request failed."
+    });
+  }
+};
+
+export const stopHttpApi = () => {
+  if (server) {
+    server.close();
+    server = null;
+  }
+  activeSettings = null;
+};
+
+const sameSettings = (a: HttpApiSettings | null, b: HttpApiSettings) =>
+  !!a &&
+  a.enabled === b.enabled &&
+  a.host === b.host &&
+  a.port === b.port &&
+  a.token === b.token &&
+  a.allowedOrigins.join("\n") === b.allowedOrigins.join("\n");
+
+export const configureHttpApi = (settings: HttpApiSettings, handlers: HttpApiHandlers) => {
+  if (sameSettings(activeSettings, settings)) {
+    return;
+  }
+  stopHttpApi();
+  activeSettings = settings;
+  if (!settings.enabled) {
+    return;
+  }
+  const next = createServer((request, response) => {
+    handleRequest(request, response, settings, handlers).catch(() => {
+      // A failure after the reply started can only abort the connection.
+      if (response.headersSent) {
+        response.destroy();
+        return;
+      }
+      sendJson(response, 500, { error: "Internal error." });
+    });
+  });
+  next.on("error", (error) => {
+    logError("http-api.failed", { port: settings.port, error: error.message }).catch(
+      () => undefined
+    );
+  });
+  next.listen(settings.port, settings.host, () => {
+    logInfo("http-api.listening", { host: settings.host, port: settings.port }).catch(
+      () => undefined
+    );
+  });
+  server = next;
+};
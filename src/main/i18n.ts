@@ -0,0 +1,110 @@
+import { app } from "electron";
+import { LanguageCatalog, UiLanguage } from "../shared/ipc";
+
+type Language = Exclude<UiLanguage, "auto">;
+
+const FALLBACK_LANGUAGE: Language = "en";
+
+const en = {
+  "watchdog.stalled.title": "Tracking stalled",
+  "watchdog.stalled.message":
+    "Tracking stalled: the last {count} captures produced no text. " +
+    "Check that the capture source is still visible.",
+  "caps.reached": "{label}: daily cap of {limit} reached.",
+  "caps.near": "{label}: {percent}% of today's cap used, {remaining} left.",
+  "alerts.fired": "{name} ({metric} {op} {threshold}, now {value})",
+  "timers.expired": "{label} expired",
+  "counters.goalReached": "{label} reached {target}",
+  "compat.disableCapture":
+    "Turn off screen capture and use manual counters or the log tail instead.",
+  "compat.disableWindowCapture":
+    "Capture a display region instead of the game window " +
+    "so the game process is never enumerated.",
+  "compat.enableClickThrough":
+    "Keep the overlay click-through so it never takes focus from the game."
+};
+
+export type MessageKey = keyof typeof en;
+
+// Notifications and webhook messages are composed in the main process, so the
+// catalogs live here too; the renderer can fetch one to show the same strings.
+const catalogs: Record<Language, Record<MessageKey, string>> = {
+  en,
+  es: {
+    "watchdog.stalled.title": "Seguimiento detenido",
+    "watchdog.stalled.message":
+      "Seguimiento detenido: las últimas {count} capturas no produjeron texto. " +
+      "Comprueba que la fuente de captura sigue visible.",
+    "caps.reached": "{label}: se alcanzó el límite diario de {limit}.",
+    "caps.near": "{label}: {percent}% del límite de hoy usado, quedan {remaining}.",
+    "alerts.fired": "{name} ({metric} {op} {threshold}, ahora {value})",
+    "timers.expired": "{label} terminó",
+    "counters.goalReached": "{label} alcanzó {target}",
+    "compat.disableCapture":
+      "Desactiva la captura de pantalla y usa contadores manuales o el seguimiento del log.",
+    "compat.disableWindowCapture":
+      "Captura una región de la pantalla en lugar de la ventana del juego " +
+      "para no enumerar su proceso.",
+    "compat.enableClickThrough":
+      "Mantén el overlay transparente a los clics para que nunca quite el foco al juego."
+  },
+  de: {
+    "watchdog.stalled.title": "Tracking angehalten",
+    "watchdog.stalled.message":
+      "Tracking angehalten: Die letzten {count} Aufnahmen enthielten keinen Text. " +
+      "Prüfe, ob die Aufnahmequelle noch sichtbar ist.",
+    "caps.reached": "{label}: Tageslimit von {limit} erreicht.",
+    "caps.near": "{label}: {percent}% des heutigen Limits verbraucht, {remaining} übrig.",
+    "alerts.fired": "{name} ({metric} {op} {threshold}, jetzt {value})",
+    "timers.expired": "{label} abgelaufen",
+    "counters.goalReached": "{label} hat {target} erreicht",
+    "compat.disableCapture":
+      "Schalte die Bildschirmaufnahme aus und nutze manuelle Zähler oder das Log-Tailing.",
+    "compat.disableWindowCapture":
+      "Nimm einen Bildschirmbereich statt des Spielfensters auf, " +
+      "damit der Spielprozess nie aufgelistet wird.",
+    "compat.enableClickThrough":
+      "Lass das Overlay klickdurchlässig, damit es dem Spiel nie den Fokus nimmt."
+  }
+};
+
+const isLanguage = (value: string): value is Language => value in catalogs;
+
+// "auto" follows the OS locale, falling back to English for languages
+// without a catalog.
+export const resolveLanguage = (setting: UiLanguage): Language => {
+  if (setting !== "auto") {
+    return setting;
+  }
+  const base = app.getLocale().split("-")[0]?.toLowerCase() ?? "";
+  return isLanguage(base) ? base : FALLBACK_LANGUAGE;
+};
+
+export const formatNumber = (language: Language, value: number, maxFractionDigits = 0) =>
+  new Intl.NumberFormat(language, { maximumFractionDigits: maxFractionDigits }).format(value);
+
+// Numeric placeholders are formatted for the language (integers without
+// decimals, other values to at most two); anything else is inserted as-is.
+export const translate = (
+  setting: UiLanguage,
+  key: MessageKey,
+  vars: Record<string, string | number> = {}
+): string => {
+  const language = resolveLanguage(setting);
+  const template = catalogs[language][key] ?? en[key];
+  return template.replace(/\{(\w+)\}/g, (whole, name: string) => {
+    const value = vars[name];
+    if (value === undefined) {
+      return whole;
+    }
+    if (typeof value !== "number") {
+      return value;
+    }
+    return formatNumber(language, value, Number.isInteger(value) ? 0 : 2);
+  });
+};
+
+export const getLanguageCatalog = (setting: UiLanguage): LanguageCatalog => {
+  const language = resolveLanguage(setting);
+  return { language, strings: { ...en, ...catalogs[language] } };
+};
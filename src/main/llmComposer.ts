@@ -224,6 +224,7 @@ const buildSystemPrompt = () =>
     "- ${rate}: computed rate per hour",
     "- ${value}: current numeric value",
     "- ${unit}: unit string",
+    "- ${eta}: time to next level (needs rolloverAt, e.g. 100 for %)",
     "",
     "Rules run only on OCR text. Use regex mode when extracting numeric values.",
     "Keep existing widgets unless the user asks to replace everything.",
@@ -0,0 +1,124 @@
+import { promises as fs } from "fs";
+import { LogTailBatch, LogTailPatternMatch, LogTailSettings } from "../shared/ipc";
+import { logError } from "./logging";
+
+const LOG_TAIL_POLL_MS = 1000;
+const LOG_TAIL_MAX_READ_BYTES = 256 * 1024;
+const LOG_TAIL_TEST_BYTES = 64 * 1024;
+const LOG_TAIL_TEST_LINES = 200;
+
+type LogTailPublisher = (batch: LogTailBatch) => void;
+
+let tailTimer: NodeJS.Timeout | null = null;
+let tailPath: string | null = null;
+let offset = 0;
+let partialLine = "";
+let polling = false;
+
+const readRange = async (path: string, start: number, length: number) => {
+  const handle = await fs.open(path, "r");
+  try {
+    const buffer = Buffer.alloc(length);
+    const { bytesRead } = await handle.read(buffer, 0, length, start);
+    return buffer.subarray(0, bytesRead).toString("utf-8");
+  } finally {
+    await handle.close();
+  }
+};
+
+const splitLines = (text: string) =>
+  text
+    .split(/\r?\n/)
+    .map((line) => line.trim())
+    .filter(Boolean);
+
+const pollLogFile = async (path: string, publish: LogTailPublisher) => {
+  const { size } = await fs.stat(path);
+  if (size < offset) {
+    // Truncated or rotated in place; start over from the beginning.
+    offset = 0;
+    partialLine = "";
+  }
+  if (size === offset) {
+    return;
+  }
+  // Skip ahead instead of replaying a huge backlog after a long pause. The
+  // skipped-to chunk starts mid-line, so that fragment and the stale partial
+  // line from before the gap are both dropped rather than glued together.
+  const start = Math.max(offset, size - LOG_TAIL_MAX_READ_BYTES);
+  const text = await readRange(path, start, size - start);
+  let chunk = partialLine + text;
+  if (start > offset) {
+    const firstBreak = text.indexOf("\n");
+    chunk = firstBreak === -1 ? "" : text.slice(firstBreak + 1);
+  }
+  offset = size;
+  const lastBreak = chunk.lastIndexOf("\n");
+  partialLine = lastBreak === -1 ? chunk : chunk.slice(lastBreak + 1);
+  const lines = splitLines(lastBreak === -1 ? "" : chunk.slice(0, lastBreak));
+  if (lines.length > 0) {
+    publish({ path, lines, readAt: Date.now() });
+  }
+};
+
+export const stopLogTail = () => {
+  if (tailTimer) {
+    clearInterval(tailTimer);
+    tailTimer = null;
+  }
+  tailPath = null;
+};
+
+// Tailing starts at the current end of the file; existing history is not
+// replayed into the rules.
+export const configureLogTail = async (settings: LogTailSettings, publish: LogTailPublisher) => {
+  const path = settings.enabled ? settings.path.trim() : "";
+  if (path && path === tailPath) {
+    return;
+  }
+  stopLogTail();
+  if (!path) {
+    return;
+  }
+  try {
+    offset = (await fs.stat(path)).size;
+  } catch {
+    offset = 0;
+  }
+  partialLine = "";
+  tailPath = path;
+  tailTimer = setInterval(() => {
+    if (polling) {
+      return;
+    }
+    polling = true;
+    pollLogFile(path, publish)
+      .catch((error: unknown) =>
+        logError("log-tail.poll.failed", {
+          path,
+          error: error instanceof Error ? error.message : String(error)
+        })
+      )
+      .finally(() => {
+        polling = false;
+      });
+  }, LOG_TAIL_POLL_MS);
+};
+
+export const testLogTailPattern = async (
+  path: string,
+  pattern: string
+): Promise<LogTailPatternMatch[]> => {
+  const regex = new RegExp(pattern);
+  const { size } = await fs.stat(path);
+  const start = Math.max(0, size - LOG_TAIL_TEST_BYTES);
+  const lines = splitLines(await readRange(path, start, size - start)).slice(
+    -LOG_TAIL_TEST_LINES
+  );
+  return lines.flatMap((line) => {
+    const match = regex.exec(line);
+    return match
+      ? [{ line, match0: match[0], groups: match.slice(1).map((value) => value ?? "") }]
+      : [];
+  });
+};
@@ -1,49 +1,199 @@
 import { app, BrowserWindow, ipcMain, screen, globalShortcut, nativeImage } from "electron";
-import { join } from "path";
+import { join, resolve as resolvePath } from "path";
 import {
   addMemoryEntry,
+  applyRetention,
+  createCounter,
+  createNote,
+  createTimer,
+  deleteCounter,
   deleteMemoryEntry,
+  deleteNote,
+  deleteTimer,
+  deleteExperiment,
+  deleteHotkey,
+  extendTimer,
+  backupProfile,
+  flushPendingWrites,
+  importExpTable,
+  getCounterHistory,
+  getPingHistory,
+  loadAlertRules,
+  loadDailyCaps,
   loadEventLog,
+  loadExperiments,
+  loadExpTables,
+  loadHotkeys,
   loadMemory,
   loadPlan,
   loadRules,
+  listCounters,
+  listNotes,
+  listTimers,
+  loadCounters,
   loadSettings,
+  loadTimers,
+  markExperiment,
   rollbackPlan,
   redoPlan,
+  restoreProfile,
+  saveAlertRules,
+  saveDailyCaps,
   saveCapture,
+  saveEventScreenshot,
+  saveCounters,
   saveEventLog,
   saveMemory,
   savePlan,
   saveRules,
   saveSettings,
-  undoPlan
+  saveTimerListOptions,
+  saveTimers,
+  setActiveExpTable,
+  pinNote,
+  searchNotes,
+  undoPlan,
+  updateCounter,
+  updateNote,
+  updateTimer,
+  upsertHotkey
 } from "./storage";
 import {
+  ActionDescriptor,
+  ActionResult,
+  AlertRulesStore,
+  CaptureImageResult,
+  CaptureRegion,
   CaptureSource,
   CaptureRoi,
+  CapturePauseInterval,
   CaptureSnapshotResult,
+  CaptureStatus,
   CaptureTarget,
+  CompatibilityReport,
+  CounterCreateInput,
+  CounterEvent,
+  CounterRecord,
+  CounterStatus,
+  CountersStore,
+  CsvExportResult,
+  DailyCapStatus,
+  DailyCapsStore,
   DisplayInfo,
+  DoNotDisturbStatus,
   EventLog,
+  ExperimentCompareOptions,
+  ExperimentComparison,
+  ExperimentMarker,
+  ExpTable,
+  ExpTablesStore,
+  ExpTableSummary,
+  GlobalSearchResult,
+  HotkeyBinding,
+  LanguageCatalog,
+  HotkeyStatus,
+  LogTailBatch,
+  LogTailPatternMatch,
   MemoryEntry,
   MemoryStore,
+  NoteEntry,
+  NoteSearchHit,
+  NoteUpdate,
   OcrResult,
   OverlaySettings,
+  OverlayWindowInfo,
+  ProfileBackupResult,
+  ProfileImportResult,
+  ProfileImportSummary,
+  OverlaySnapshot,
+  PingHistorySample,
+  PingStats,
   PlannerComposeInput,
   PlanSaveMeta,
-  RulesStore
+  RateReading,
+  RateSample,
+  RetentionReport,
+  RulesStore,
+  ServerTimeInfo,
+  SpectatorSnapshot,
+  SystemStats,
+  TimerCreateInput,
+  TimerListOptions,
+  TimerListResult,
+  TimerRecord,
+  TimerUpdate,
+  TimersStore,
+  WidgetExportFormat,
+  WidgetVisibilityState
 } from "../shared/ipc";
 import { runOcr, shutdownOcrWorker } from "./ocr";
 import * as ocrPreprocess from "./ocrPreprocess";
 import { logError, logInfo } from "./logging";
 import { composeWithLlm } from "./llmComposer";
+import { globalSearch } from "./search";
+import { ActionContext, executeAction, listActions } from "./actions";
+import {
+  applyCounterDelta,
+  applyCounterStep,
+  findReachedGoals,
+  getCounterStatus,
+  resetCounterValue,
+  setCounterValue
+} from "./counters";
+import { parseQuickEntry } from "../shared/numberParsing";
+import { startAlertEngine, stopAlertEngine } from "./alerts";
+import { recordCaptureResult, resetCaptureWatchdog } from "./captureWatchdog";
+import { createNotificationRouter, getDoNotDisturbStatus } from "./notifications";
+import {
+  buildOverlaySnapshot,
+  stopOverlayState,
+  subscribeOverlayState,
+  unsubscribeOverlayState
+} from "./overlayState";
+import { compareExperiment } from "./experiments";
+import { exportCounterHistoryCsv, exportEventLogCsv } from "./csvExport";
+import { exportWidgetData } from "./widgetExport";
+import { configureHttpApi, HttpApiHandlers, stopHttpApi } from "./httpApi";
+import { compactEventLog, startRetention, stopRetention } from "./retention";
+import { checkCompatibility } from "./compatibility";
+import { getLanguageCatalog } from "./i18n";
+import { configureSpectator, getSpectatorSnapshot, stopSpectator } from "./spectator";
+import { readGainTotal } from "../shared/gainTotals";
+import { flattenWidgets } from "../widgetSpec";
+import { getDailyCapStatuses, startDailyCapMonitor, stopDailyCapMonitor } from "./dailyCaps";
+import {
+  discardStagedProfileImport,
+  findDeepLink,
+  getStagedProfileImport,
+  PROFILE_PROTOCOL,
+  stageProfileImport,
+  takeStagedProfileImport
+} from "./profileImport";
+import { experimentCompareOptionsSchema } from "../shared/experimentsSchema";
+import { getHotkeyStatuses, registerHotkeys, unregisterHotkeys } from "./hotkeys";
+import { configureLogTail, stopLogTail, testLogTailPattern } from "./logTail";
+import { configurePingMonitor, getPingStats, stopPingMonitor } from "./pingMonitor";
+import { startTimerEngine, stopTimerEngine } from "./timerEngine";
+import { getSystemStats, startSystemStats, stopSystemStats } from "./systemStats";
+import { getServerTime, startServerClock, stopServerClock, syncServerClock } from "./serverClock";
+import {
+  refreshWidgetVisibility,
+  startVisibilityEngine,
+  stopVisibilityEngine
+} from "./visibility";
 import screenshotDesktop from "screenshot-desktop";
 import { execFile } from "child_process";
 
 let overlayWindow: BrowserWindow | null = null;
+let capturePause: { reason: string; startedAt: number } | null = null;
+let captureTicker: { timer: NodeJS.Timeout; intervalMs: number } | null = null;
+const monitorWindows = new Map<number, BrowserWindow>();
 let cachedSettings: OverlaySettings | null = null;
+let shutdownState: "running" | "flushing" | "done" = "running";
 
 const escapeShortcut = "Control+Shift+O";
+const OVERLAY_WINDOW_LABEL = "overlay";
+const SHUTDOWN_FLUSH_TIMEOUT_MS = 3000;
 const OCR_MAX_WIDTH = 1920;
 const OCR_MAX_HEIGHT = 1080;
 const OCR_UPSCALE_TARGET_LONG_SIDE = 1400;
@@ -208,6 +358,260 @@ const positionOnDisplay = (window: BrowserWindow, displayId: number | null) => {
   window.setBounds(nextBounds);
 };
 
+const sendToOverlay = (channel: string, payload?: unknown) => {
+  if (!overlayWindow || overlayWindow.isDestroyed()) {
+    return;
+  }
+  overlayWindow.webContents.send(channel, payload);
+};
+
+// Monitor windows render read-only copies of the plan, so they are told about
+// saved plans and event logs instead of polling storage.
+const sendToMonitorWindows = (channel: string, payload?: unknown) => {
+  monitorWindows.forEach((window) => {
+    if (!window.isDestroyed()) {
+      window.webContents.send(channel, payload);
+    }
+  });
+};
+
+const publishLogTailLines = (batch: LogTailBatch) => sendToOverlay("log-tail:lines", batch);
+const publishPingStats = (stats: PingStats) => sendToOverlay("ping:sample", stats);
+
+const publishSpectatorSnapshot = (snapshot: SpectatorSnapshot) => {
+  sendToOverlay("spectator:snapshot", snapshot);
+  sendToMonitorWindows("spectator:snapshot", snapshot);
+};
+
+const rendererQuery = (settings: OverlaySettings, query: Record<string, string> = {}) =>
+  settings.spectator.enabled ? { ...query, spectator: "1" } : query;
+
+// Switching spectator mode swaps the overlay between the full app and the
+// read-only mirror, so every window is reloaded with the new query.
+const reloadForSpectatorMode = (settings: OverlaySettings) => {
+  if (overlayWindow && !overlayWindow.isDestroyed()) {
+    loadRenderer(overlayWindow, rendererQuery(settings)).catch(() => undefined);
+  }
+  monitorWindows.forEach((window, monitorId) => {
+    if (!window.isDestroyed()) {
+      loadRenderer(window, rendererQuery(settings, { monitor: String(monitorId) })).catch(
+        () => undefined
+      );
+    }
+  });
+};
+
+const applySettings = async (requested: OverlaySettings) => {
+  const previous = cachedSettings;
+  // A spectator mirrors another instance and never samples locally.
+  const settings =
+    requested.spectator.enabled && requested.captureEnabled
+      ? { ...requested, captureEnabled: false }
+      : requested;
+  cachedSettings = settings;
+  await saveSettings(settings);
+  if (overlayWindow) {
+    overlayWindow.setOpacity(settings.opacity);
+    applyClickThrough(overlayWindow, settings.clickThrough);
+  }
+  monitorWindows.forEach((window) => window.setOpacity(settings.opacity));
+  if (!settings.captureEnabled) {
+    resetCaptureWatchdog();
+    capturePause = null;
+  }
+  configureCaptureTicker(settings);
+  refreshWidgetVisibility().catch(() => undefined);
+  configurePingMonitor(settings.pingMonitor, publishPingStats);
+  configureHttpApi(settings.httpApi, httpApiHandlers);
+  configureSpectator(settings.spectator, publishSpectatorSnapshot);
+  await configureLogTail(settings.logTail, publishLogTailLines);
+  if (previous && previous.spectator.enabled !== settings.spectator.enabled) {
+    setImmediate(() => reloadForSpectatorMode(settings));
+  }
+};
+
+const stopCaptureTicker = () => {
+  if (captureTicker) {
+    clearInterval(captureTicker.timer);
+    captureTicker = null;
+  }
+};
+
+// The capture cadence lives here rather than in the renderer: while capture
+// is enabled the overlay is told when to sample, and paused ticks are dropped.
+const configureCaptureTicker = (settings: OverlaySettings) => {
+  const intervalMs = settings.captureEnabled ? settings.overlayRefresh.captureMs : null;
+  if (captureTicker && captureTicker.intervalMs === intervalMs) {
+    return;
+  }
+  stopCaptureTicker();
+  if (intervalMs === null) {
+    return;
+  }
+  const timer = setInterval(() => {
+    if (!capturePause) {
+      sendToOverlay("capture:tick", Date.now());
+    }
+  }, intervalMs);
+  captureTicker = { timer, intervalMs };
+};
+
+const resolveCaptureStatus = (settings: OverlaySettings): CaptureStatus => {
+  if (!settings.captureEnabled) {
+    return "stopped";
+  }
+  return capturePause ? "paused" : "running";
+};
+
+// Pausing keeps captureEnabled (and the capture ticker) intact; ticks are
+// skipped until resumed, so no source or ROI needs re-selecting. Resuming
+// reports the finished interval so the renderer can keep it out of rates.
+const setCapturePaused = async (paused: boolean, reason = "paused"): Promise<CaptureStatus> => {
+  const settings = cachedSettings ?? (await loadSettings());
+  if (!settings.captureEnabled) {
+    throw new Error("Capture is not running.");
+  }
+  const previous = capturePause;
+  if (paused && !previous) {
+    capturePause = { reason: reason.trim() || "paused", startedAt: Date.now() };
+  }
+  if (!paused && previous) {
+    capturePause = null;
+    const interval: CapturePauseInterval = { ...previous, endedAt: Date.now() };
+    sendToOverlay("capture:pause-ended", interval);
+  }
+  const status = resolveCaptureStatus(settings);
+  sendToOverlay("capture:status-changed", status);
+  await logInfo(paused ? "capture.paused" : "capture.resumed", capturePause ?? previous);
+  return status;
+};
+
+const updateCounterAndNotify = async (
+  counterId: string,
+  update: (counter: CounterRecord) => CounterRecord
+): Promise<CounterStatus> => {
+  const { before, after } = await updateCounter(counterId, update);
+  const { language } = cachedSettings ?? (await loadSettings());
+  findReachedGoals([before], [after], Date.now(), language).forEach((goal) => {
+    sendToOverlay("counters:goal-reached", goal);
+  });
+  return getCounterStatus(after);
+};
+
+// Remote writes go through the same paths as IPC so goal notifications fire.
+const HTTP_EVENT_LOG_LIMIT = 200;
+
+// Rates are read from trackRate rule state plus the text the rule last wrote
+// to its widget, so external tools see exactly what the overlay shows.
+const listRateReadings = async (): Promise<RateReading[]> => {
+  const [rules, { plan }] = await Promise.all([loadRules(), loadPlan()]);
+  const widgets = flattenWidgets(plan?.widgets ?? []);
+  const now = Date.now();
+  return rules.rules.flatMap((rule) => {
+    if (rule.action.type !== "trackRate") {
+      return [];
+    }
+    const { widgetId } = rule.action;
+    const text = widgets.find((widget) => widget.id === widgetId)?.text;
+    return [
+      {
+        ruleId: rule.id,
+        widgetId,
+        display: typeof text === "string" ? text : null,
+        value: rule.state?.lastValue ?? null,
+        lastAt: rule.state?.lastAt ?? null,
+        sittingGained: readGainTotal(rule.state, "sitting", now),
+        todayGained: readGainTotal(rule.state, "today", now)
+      }
+    ];
+  });
+};
+
+const listRateSamples = async (ruleId: string): Promise<RateSample[]> => {
+  const rule = (await loadRules()).rules.find((item) => item.id === ruleId);
+  if (!rule || rule.action.type !== "trackRate") {
+    throw new Error(`Rate rule ${ruleId} not found.`);
+  }
+  const samples = rule.state?.samples ?? [];
+  const { lastAt, lastValue } = rule.state ?? {};
+  return lastAt !== undefined && lastValue !== undefined && !samples.some((s) => s.at === lastAt)
+    ? [...samples, { at: lastAt, total: lastValue }]
+    : samples;
+};
+
+const httpApiHandlers: HttpApiHandlers = {
+  incrementCounter: (counterId, delta) =>
+    updateCounterAndNotify(counterId, (counter) => applyCounterDelta(counter, delta)),
+  createTimer,
+  getStatus: async () => ({
+    capture: resolveCaptureStatus(cachedSettings ?? (await loadSettings())),
+    at: Date.now()
+  }),
+  listRates: listRateReadings,
+  listRateSamples,
+  listCounters,
+  listTimers: () => listTimers(),
+  listDailyCaps: () => getDailyCapStatuses(),
+  getPlan: async () => (await loadPlan()).plan,
+  getEventLog: async () => {
+    const log = await loadEventLog();
+    return { ...log, entries: log.entries.slice(-HTTP_EVENT_LOG_LIMIT) };
+  }
+};
+
+const stepCounterAndNotify = (counterId: string, direction: 1 | -1) =>
+  updateCounterAndNotify(counterId, (counter) => applyCounterStep(counter, direction));
+
+// Actions run outside the renderer (palette, hotkeys, integrations), so any
+// state they change is pushed back to the overlay.
+const actionContext: ActionContext = {
+  getSettings: async () => {
+    cachedSettings = cachedSettings ?? (await loadSettings());
+    return cachedSettings;
+  },
+  applySettings: async (settings) => {
+    await applySettings(settings);
+    sendToOverlay("app:settings-changed", settings);
+  },
+  publishPlan: (plan) => {
+    sendToOverlay("plan:changed", plan);
+    sendToMonitorWindows("plan:changed", plan);
+  },
+  stepCounter: stepCounterAndNotify,
+  getCaptureStatus: async () => resolveCaptureStatus(cachedSettings ?? (await loadSettings())),
+  setCapturePaused,
+  toggleOverlayVisibility: () => {
+    if (!overlayWindow || overlayWindow.isDestroyed()) {
+      return false;
+    }
+    if (overlayWindow.isVisible()) {
+      overlayWindow.hide();
+      return false;
+    }
+    overlayWindow.showInactive();
+    return true;
+  }
+};
+
+const applyHotkeys = async (): Promise<HotkeyStatus[]> => {
+  const store = await loadHotkeys();
+  return registerHotkeys(store.bindings, (binding) => {
+    executeAction(binding.actionId, binding.args, actionContext)
+      .then((result) => {
+        if (!result.ok) {
+          logError("hotkeys.action.failed", { bindingId: binding.id, error: result.error });
+        }
+      })
+      .catch(() => undefined);
+  });
+};
+
+const notificationRouter = createNotificationRouter(
+  sendToOverlay,
+  actionContext.getSettings,
+  (action, event) => captureEventScreenshot(action.monitorId, event.name, action.region)
+);
+
 const rectsIntersect = (a: Electron.Rectangle, b: Electron.Rectangle) => {
   const ax2 = a.x + a.width;
   const ay2 = a.y + a.height;
@@ -641,6 +1045,53 @@ const captureWindowById = async (windowId: string) => {
   };
 };
 
+// Region coordinates are physical pixels of the captured monitor image and are
+// clamped to its bounds.
+const captureMonitorImage = async (
+  monitorId: string,
+  region?: CaptureRegion
+): Promise<CaptureImageResult> => {
+  const capture = await captureDisplayById(monitorId);
+  let native = nativeImage.createFromBuffer(capture.image);
+  if (region) {
+    const rect = normalizeCropRect(
+      {
+        x: Math.round(region.x),
+        y: Math.round(region.y),
+        width: Math.round(region.width),
+        height: Math.round(region.height)
+      },
+      native.getSize()
+    );
+    if (!rect) {
+      throw new Error("Capture region is outside the monitor.");
+    }
+    native = native.crop(rect);
+  }
+  const size = native.getSize();
+  return {
+    sourceId: capture.sourceId,
+    sourceName: capture.sourceName,
+    capturedAt: capture.capturedAt,
+    width: size.width,
+    height: size.height,
+    pngBase64: native.toPNG().toString("base64")
+  };
+};
+
+const captureEventScreenshot = async (
+  monitorId: string,
+  label: string,
+  region?: CaptureRegion
+): Promise<string> => {
+  const capture = await captureMonitorImage(monitorId, region);
+  return saveEventScreenshot(
+    Buffer.from(capture.pngBase64, "base64"),
+    label,
+    capture.capturedAt
+  );
+};
+
 const captureFromTarget = async (target: CaptureTarget) => {
   if (target.type === "display") {
     return captureDisplayById(target.id);
@@ -717,6 +1168,54 @@ const pickBetterOcrText = (
 
 
 
+const reportCaptureResult = async (producedText: boolean) => {
+  const settings = await actionContext.getSettings();
+  await recordCaptureResult(
+    settings.captureWatchdog,
+    producedText,
+    notificationRouter,
+    settings.language
+  ).catch((error: unknown) =>
+    logError("capture.watchdog.failed", { error: formatError(error) })
+  );
+};
+
+const summarizeExpTables = (store: ExpTablesStore): ExpTableSummary[] =>
+  store.tables.map((table) => ({
+    id: table.id,
+    name: table.name,
+    importedAt: table.importedAt,
+    levelCount: table.levels.length,
+    maxLevel: table.levels[table.levels.length - 1]?.level ?? 0,
+    active: table.id === store.activeTableId
+  }));
+
+const runRetention = async (): Promise<RetentionReport> => {
+  const settings = await actionContext.getSettings();
+  const report = await applyRetention(settings.retention);
+  await logInfo("retention.ran", report);
+  return report;
+};
+
+const handleDeepLink = (link: string) => {
+  stageProfileImport(link)
+    .then((summary) => sendToOverlay("profile:import-staged", summary))
+    .catch((error: unknown) =>
+      logError("profile.import.failed", { link, error: formatError(error) })
+    );
+};
+
+const loadRenderer = async (window: BrowserWindow, query?: Record<string, string>) => {
+  const devServerUrl = process.env.VITE_DEV_SERVER_URL;
+  if (devServerUrl) {
+    const url = new URL(devServerUrl);
+    Object.entries(query ?? {}).forEach(([key, value]) => url.searchParams.set(key, value));
+    await window.loadURL(url.toString());
+  } else {
+    await window.loadFile(join(__dirname, "..", "renderer", "index.html"), { query });
+  }
+};
+
 const createOverlayWindow = async () => {
   const initialSettings = await loadSettings();
   cachedSettings = initialSettings;
@@ -758,12 +1257,7 @@ const createOverlayWindow = async () => {
     }
   }
 
-  const devServerUrl = process.env.VITE_DEV_SERVER_URL;
-  if (devServerUrl) {
-    await overlayWindow.loadURL(devServerUrl);
-  } else {
-    await overlayWindow.loadFile(join(__dirname, "..", "renderer", "index.html"));
-  }
+  await loadRenderer(overlayWindow, rendererQuery(initialSettings));
 
   const persistBounds = async () => {
     if (!overlayWindow || !cachedSettings) {
@@ -788,7 +1282,14 @@ const createOverlayWindow = async () => {
   overlayWindow.on("will-move", schedulePersist);
   overlayWindow.on("resize", schedulePersist);
 
-  overlayWindow.on("close", () => {
+  overlayWindow.on("close", (event) => {
+    if (shutdownState === "running") {
+      // Route window close through before-quit so the renderer can flush first.
+      event.preventDefault();
+      persistBounds().catch(() => undefined);
+      app.quit();
+      return;
+    }
     if (boundsTimer) {
       clearTimeout(boundsTimer);
       boundsTimer = null;
@@ -808,6 +1309,64 @@ const createOverlayWindow = async () => {
   });
 };
 
+const describeMonitorWindow = (monitorId: number, window: BrowserWindow): OverlayWindowInfo => ({
+  monitorId,
+  label: `monitor-${monitorId}`,
+  bounds: window.getBounds()
+});
+
+// Monitor windows mirror the current plan read-only across a whole display.
+// They never take input, so the main overlay stays the only editor and the
+// only writer of plan and event log state.
+const createMonitorWindow = async (monitorId: number): Promise<OverlayWindowInfo> => {
+  const existing = monitorWindows.get(monitorId);
+  if (existing && !existing.isDestroyed()) {
+    return describeMonitorWindow(monitorId, existing);
+  }
+  const display = screen.getAllDisplays().find((candidate) => candidate.id === monitorId);
+  if (!display) {
+    throw new Error(`Unknown monitor ${monitorId}.`);
+  }
+  const settings = await actionContext.getSettings();
+  const window = new BrowserWindow({
+    ...display.bounds,
+    transparent: true,
+    frame: false,
+    alwaysOnTop: true,
+    resizable: false,
+    movable: false,
+    focusable: false,
+    hasShadow: false,
+    skipTaskbar: true,
+    webPreferences: {
+      contextIsolation: true,
+      preload: join(__dirname, "..", "preload", "preload.js"),
+      sandbox: false,
+      nodeIntegration: false
+    }
+  });
+  window.setAlwaysOnTop(true, "screen-saver");
+  window.setVisibleOnAllWorkspaces(true, { visibleOnFullScreen: true });
+  window.setOpacity(settings.opacity);
+  window.setIgnoreMouseEvents(true);
+  monitorWindows.set(monitorId, window);
+  window.on("closed", () => {
+    if (monitorWindows.get(monitorId) === window) {
+      monitorWindows.delete(monitorId);
+    }
+  });
+  await loadRenderer(window, rendererQuery(settings, { monitor: String(monitorId) }));
+  return describeMonitorWindow(monitorId, window);
+};
+
+const closeMonitorWindow = (monitorId: number) => {
+  const window = monitorWindows.get(monitorId);
+  monitorWindows.delete(monitorId);
+  if (window && !window.isDestroyed()) {
+    window.close();
+  }
+};
+
 const registerIpc = () => {
   ipcMain.handle("app:get-settings", async () => {
     cachedSettings = cachedSettings ?? (await loadSettings());
@@ -815,14 +1374,46 @@ const registerIpc = () => {
   });
 
   ipcMain.handle("app:save-settings", async (_event, settings: OverlaySettings) => {
-    cachedSettings = settings;
-    await saveSettings(settings);
-    if (overlayWindow) {
-      overlayWindow.setOpacity(settings.opacity);
-      applyClickThrough(overlayWindow, settings.clickThrough);
+    await applySettings(settings);
+  });
+
+  ipcMain.handle(
+    "overlay:get-clickthrough",
+    async (_event, windowLabel = OVERLAY_WINDOW_LABEL): Promise<boolean> => {
+      if (windowLabel !== OVERLAY_WINDOW_LABEL) {
+        throw new Error(`Unknown overlay window "${windowLabel}".`);
+      }
+      return (await actionContext.getSettings()).clickThrough;
     }
+  );
+
+  ipcMain.handle(
+    "overlay:set-clickthrough",
+    async (_event, windowLabel: string, enabled: boolean): Promise<boolean> => {
+      if (windowLabel !== OVERLAY_WINDOW_LABEL) {
+        throw new Error(`Unknown overlay window "${windowLabel}".`);
+      }
+      const settings = await actionContext.getSettings();
+      await actionContext.applySettings({ ...settings, clickThrough: enabled });
+      return enabled;
+    }
+  );
+
+  ipcMain.handle(
+    "overlay:create-window",
+    async (_event, monitorId: number): Promise<OverlayWindowInfo> => createMonitorWindow(monitorId)
+  );
+
+  ipcMain.handle("overlay:close-window", async (_event, monitorId: number) => {
+    closeMonitorWindow(monitorId);
   });
 
+  ipcMain.handle("overlay:list-windows", async (): Promise<OverlayWindowInfo[]> =>
+    Array.from(monitorWindows.entries())
+      .filter(([, window]) => !window.isDestroyed())
+      .map(([monitorId, window]) => describeMonitorWindow(monitorId, window))
+  );
+
   ipcMain.handle("app:get-displays", async () => getDisplays());
 
   ipcMain.handle("app:set-display", async (_event, displayId: number) => {
@@ -836,15 +1427,22 @@ const registerIpc = () => {
 
   ipcMain.handle("plan:load", async () => loadPlan());
 
+  const mirrorPlan = <T>(plan: T) => {
+    sendToMonitorWindows("plan:changed", plan);
+    return plan;
+  };
+
   ipcMain.handle("plan:save", async (_event, plan: unknown, meta?: PlanSaveMeta) => {
-    return savePlan(plan, meta);
+    return mirrorPlan(await savePlan(plan, meta));
   });
 
-  ipcMain.handle("plan:rollback", async (_event, snapshotId: string) => rollbackPlan(snapshotId));
+  ipcMain.handle("plan:rollback", async (_event, snapshotId: string) =>
+    mirrorPlan(await rollbackPlan(snapshotId))
+  );
 
-  ipcMain.handle("plan:undo", async () => undoPlan());
+  ipcMain.handle("plan:undo", async () => mirrorPlan(await undoPlan()));
 
-  ipcMain.handle("plan:redo", async () => redoPlan());
+  ipcMain.handle("plan:redo", async () => mirrorPlan(await redoPlan()));
 
   ipcMain.handle(
     "planner:compose",
@@ -860,7 +1458,10 @@ const registerIpc = () => {
   ipcMain.handle("event-log:load", async () => loadEventLog());
 
   ipcMain.handle("event-log:save", async (_event, log: EventLog) => {
-    await saveEventLog(log);
+    const settings = await actionContext.getSettings();
+    const compacted = compactEventLog(log, settings.retention).log;
+    await saveEventLog(compacted);
+    sendToMonitorWindows("event-log:changed", compacted);
   });
 
   ipcMain.handle("memory:load", async (): Promise<MemoryStore> => {
@@ -879,6 +1480,52 @@ const registerIpc = () => {
     return deleteMemoryEntry(entryId);
   });
 
+  ipcMain.handle(
+    "notes:create",
+    async (_event, text: string, tags?: string[]): Promise<NoteEntry> => {
+      return createNote(text, tags);
+    }
+  );
+
+  ipcMain.handle("notes:list", async (): Promise<NoteEntry[]> => {
+    return listNotes();
+  });
+
+  ipcMain.handle(
+    "notes:update",
+    async (_event, noteId: string, update: NoteUpdate): Promise<NoteEntry> => {
+      return updateNote(noteId, update);
+    }
+  );
+
+  ipcMain.handle("notes:delete", async (_event, noteId: string) => {
+    await deleteNote(noteId);
+  });
+
+  ipcMain.handle(
+    "notes:pin",
+    async (_event, noteId: string, pinned: boolean): Promise<NoteEntry> => {
+      return pinNote(noteId, pinned);
+    }
+  );
+
+  ipcMain.handle("memory:search-notes", async (_event, query: string): Promise<NoteSearchHit[]> => {
+    return searchNotes(query);
+  });
+
+  ipcMain.handle("search:global", async (_event, query: string): Promise<GlobalSearchResult[]> => {
+    return globalSearch(query);
+  });
+
+  ipcMain.handle("actions:list", async (): Promise<ActionDescriptor[]> => listActions());
+
+  ipcMain.handle(
+    "actions:execute",
+    async (_event, actionId: string, args?: Record<string, unknown>): Promise<ActionResult> => {
+      return executeAction(actionId, args, actionContext);
+    }
+  );
+
   ipcMain.handle("rules:load", async (): Promise<RulesStore> => {
     return loadRules();
   });
@@ -887,10 +1534,466 @@ const registerIpc = () => {
     await saveRules(store);
   });
 
+  ipcMain.handle("timers:load", async (): Promise<TimersStore> => {
+    return loadTimers();
+  });
+
+  ipcMain.handle("timers:save", async (_event, store: TimersStore) => {
+    await saveTimers(store);
+  });
+
+  ipcMain.handle(
+    "timers:list",
+    async (_event, options?: Partial<TimerListOptions>): Promise<TimerListResult> => {
+      return listTimers(options);
+    }
+  );
+
+  ipcMain.handle(
+    "timers:set-list-options",
+    async (_event, options: TimerListOptions): Promise<TimerListOptions> => {
+      return saveTimerListOptions(options);
+    }
+  );
+
+  ipcMain.handle(
+    "timers:create",
+    async (_event, input: TimerCreateInput): Promise<TimerRecord> => {
+      return createTimer(input);
+    }
+  );
+
+  ipcMain.handle(
+    "timers:update",
+    async (_event, timerId: string, update: TimerUpdate): Promise<TimerRecord> => {
+      return updateTimer(timerId, update);
+    }
+  );
+
+  ipcMain.handle("timers:delete", async (_event, timerId: string) => {
+    await deleteTimer(timerId);
+  });
+
+  ipcMain.handle(
+    "timers:extend",
+    async (_event, timerId: string, seconds: number): Promise<TimerRecord> => {
+      return extendTimer(timerId, seconds);
+    }
+  );
+
+  ipcMain.handle("counters:load", async (): Promise<CountersStore> => {
+    return loadCounters();
+  });
+
+  ipcMain.handle("counters:save", async (_event, store: CountersStore) => {
+    const previous = await saveCounters(store);
+    const { language } = await actionContext.getSettings();
+    findReachedGoals(previous.counters, store.counters, Date.now(), language).forEach((goal) => {
+      sendToOverlay("counters:goal-reached", goal);
+    });
+  });
+
+  ipcMain.handle("counters:list", async (): Promise<CounterStatus[]> => {
+    return listCounters();
+  });
+
+  ipcMain.handle(
+    "counters:history",
+    async (_event, counterId: string, from?: number, to?: number): Promise<CounterEvent[]> => {
+      return getCounterHistory(counterId, from, to);
+    }
+  );
+
+  ipcMain.handle(
+    "export:counter-history-csv",
+    async (
+      _event,
+      path: string,
+      counterId?: string,
+      from?: number,
+      to?: number
+    ): Promise<CsvExportResult> => {
+      const counters = (await loadCounters()).counters;
+      const ids = counterId ? [counterId] : counters.map((counter) => counter.id);
+      const events = (await Promise.all(ids.map((id) => getCounterHistory(id, from, to))))
+        .flat()
+        .sort((a, b) => a.timestamp - b.timestamp);
+      const names = new Map(counters.map((counter) => [counter.id, counter.label]));
+      return exportCounterHistoryCsv(path, events, names);
+    }
+  );
+
+  ipcMain.handle(
+    "export:event-log-csv",
+    async (_event, path: string, from?: number, to?: number): Promise<CsvExportResult> => {
+      const entries = (await loadEventLog()).entries
+        .filter(
+          (entry) =>
+            (from === undefined || entry.timestamp >= from) &&
+            (to === undefined || entry.timestamp <= to)
+        )
+        .sort((a, b) => a.timestamp - b.timestamp);
+      return exportEventLogCsv(path, entries);
+    }
+  );
+
+  ipcMain.handle(
+    "export:widget-data",
+    async (
+      _event,
+      path: string,
+      widgetId: string,
+      format: WidgetExportFormat,
+      from?: number,
+      to?: number
+    ): Promise<CsvExportResult> => {
+      const [{ plan }, eventLog, counters, rules] = await Promise.all([
+        loadPlan(),
+        loadEventLog(),
+        loadCounters(),
+        loadRules()
+      ]);
+      return exportWidgetData(
+        path,
+        widgetId,
+        format === "json" ? "json" : "csv",
+        { plan, eventLog, counters, rules, counterHistory: getCounterHistory },
+        from,
+        to
+      );
+    }
+  );
+
+  ipcMain.handle(
+    "counters:step",
+    async (_event, counterId: string, direction: 1 | -1): Promise<CounterStatus> => {
+      return stepCounterAndNotify(counterId, direction < 0 ? -1 : 1);
+    }
+  );
+
+  ipcMain.handle("overlay:get-snapshot", async (): Promise<OverlaySnapshot> => {
+    return buildOverlaySnapshot(await actionContext.getSettings());
+  });
+
+  ipcMain.handle("overlay:subscribe-state", async (event): Promise<OverlaySnapshot> => {
+    return subscribeOverlayState(event.sender, actionContext.getSettings);
+  });
+
+  ipcMain.handle("overlay:unsubscribe-state", async (event) => {
+    unsubscribeOverlayState(event.sender.id);
+  });
+
+  ipcMain.handle("widgets:visibility", async (): Promise<WidgetVisibilityState> => {
+    return refreshWidgetVisibility();
+  });
+
+  ipcMain.handle("clock:get-server-time", async (): Promise<ServerTimeInfo> => {
+    const settings = await actionContext.getSettings();
+    return getServerTime(settings.serverClock);
+  });
+
+  ipcMain.handle("clock:sync", async (): Promise<ServerTimeInfo> => {
+    const settings = await actionContext.getSettings();
+    await syncServerClock(settings.serverClock);
+    return getServerTime(settings.serverClock);
+  });
+
+  ipcMain.handle("ping:get-stats", async (): Promise<PingStats> => {
+    return getPingStats();
+  });
+
+  ipcMain.handle(
+    "ping:get-history",
+    async (_event, from?: number, to?: number): Promise<PingHistorySample[]> => {
+      return getPingHistory(from, to);
+    }
+  );
+
+  ipcMain.handle("system:get-stats", async (): Promise<SystemStats> => {
+    return getSystemStats();
+  });
+
+  ipcMain.handle("log-tail:set-path", async (_event, path: string): Promise<OverlaySettings> => {
+    const settings = await actionContext.getSettings();
+    const next = { ...settings, logTail: { ...settings.logTail, path: path.trim() } };
+    await actionContext.applySettings(next);
+    return next;
+  });
+
+  ipcMain.handle(
+    "log-tail:test-pattern",
+    async (_event, pattern: string): Promise<LogTailPatternMatch[]> => {
+      const settings = await actionContext.getSettings();
+      if (!settings.logTail.path) {
+        throw new Error("Log file path is not set.");
+      }
+      return testLogTailPattern(settings.logTail.path, pattern);
+    }
+  );
+
+  ipcMain.handle("notifications:get-dnd", async (): Promise<DoNotDisturbStatus> => {
+    const settings = await actionContext.getSettings();
+    return getDoNotDisturbStatus(settings);
+  });
+
+  ipcMain.handle(
+    "notifications:set-dnd",
+    async (_event, enabled: boolean, until?: number | null): Promise<DoNotDisturbStatus> => {
+      const settings = await actionContext.getSettings();
+      const next = {
+        ...settings,
+        doNotDisturb: { enabled, until: enabled ? until ?? null : null }
+      };
+      await actionContext.applySettings(next);
+      return getDoNotDisturbStatus(next);
+    }
+  );
+
+  ipcMain.handle("hotkeys:list", async (): Promise<HotkeyStatus[]> => {
+    return getHotkeyStatuses();
+  });
+
+  ipcMain.handle(
+    "hotkeys:set",
+    async (_event, binding: HotkeyBinding): Promise<HotkeyStatus[]> => {
+      if (!listActions().some((action) => action.id === binding.actionId)) {
+        throw new Error(`Unknown action "${binding.actionId}".`);
+      }
+      await upsertHotkey(binding);
+      return applyHotkeys();
+    }
+  );
+
+  ipcMain.handle(
+    "hotkeys:delete",
+    async (_event, bindingId: string): Promise<HotkeyStatus[]> => {
+      await deleteHotkey(bindingId);
+      return applyHotkeys();
+    }
+  );
+
+  ipcMain.handle("spectator:get-snapshot", async (): Promise<SpectatorSnapshot> => {
+    return getSpectatorSnapshot();
+  });
+
+  ipcMain.handle("i18n:get-catalog", async (): Promise<LanguageCatalog> => {
+    return getLanguageCatalog((await actionContext.getSettings()).language);
+  });
+
+  ipcMain.handle("retention:run-now", async (): Promise<RetentionReport> => {
+    return runRetention();
+  });
+
+  ipcMain.handle(
+    "compatibility:report",
+    async (_event, refresh?: boolean): Promise<CompatibilityReport> => {
+      const { language } = await actionContext.getSettings();
+      return checkCompatibility(language, Boolean(refresh));
+    }
+  );
+
+  ipcMain.handle(
+    "profile:backup",
+    async (_event, destPath: string): Promise<ProfileBackupResult> => backupProfile(destPath)
+  );
+
+  // Capture is switched off first so no OCR tick writes into the profile
+  // mid-restore; windows reload afterwards to drop stale renderer state.
+  ipcMain.handle(
+    "profile:restore",
+    async (_event, srcPath: string): Promise<ProfileBackupResult> => {
+      const current = await actionContext.getSettings();
+      if (current.captureEnabled) {
+        await actionContext.applySettings({ ...current, captureEnabled: false });
+      }
+      const result = await restoreProfile(srcPath);
+      cachedSettings = null;
+      await applySettings(await loadSettings());
+      await applyHotkeys();
+      await logInfo("profile.restored", result);
+      setImmediate(() => {
+        overlayWindow?.reload();
+        monitorWindows.forEach((window) => window.reload());
+      });
+      return result;
+    }
+  );
+
+  ipcMain.handle("profile:get-staged-import", async (): Promise<ProfileImportSummary | null> => {
+    return getStagedProfileImport();
+  });
+
+  ipcMain.handle("profile:apply-staged-import", async (): Promise<ProfileImportResult> => {
+    const staged = takeStagedProfileImport();
+    if (!staged) {
+      throw new Error("No profile import is staged.");
+    }
+    const plan = await savePlan(staged.plan, { reason: "profile:import", actor: "user" });
+    if (staged.rules) {
+      await saveRules(staged.rules);
+    }
+    actionContext.publishPlan(plan);
+    await logInfo("profile.import.applied", staged.summary);
+    return { plan, ...(staged.rules ? { rules: staged.rules } : {}) };
+  });
+
+  ipcMain.handle("profile:discard-staged-import", async () => {
+    discardStagedProfileImport();
+  });
+
+  ipcMain.handle(
+    "exp-tables:import",
+    async (_event, name: string, content: string): Promise<ExpTableSummary> => {
+      const table = await importExpTable(name, content);
+      const summary = summarizeExpTables(await loadExpTables()).find(
+        (candidate) => candidate.id === table.id
+      );
+      if (!summary) {
+        throw new Error(`Exp table "${name}" was not saved.`);
+      }
+      return summary;
+    }
+  );
+
+  ipcMain.handle("exp-tables:list", async (): Promise<ExpTableSummary[]> => {
+    return summarizeExpTables(await loadExpTables());
+  });
+
+  ipcMain.handle(
+    "exp-tables:set-active",
+    async (_event, tableId: string | null): Promise<ExpTableSummary[]> => {
+      return summarizeExpTables(await setActiveExpTable(tableId));
+    }
+  );
+
+  ipcMain.handle("exp-tables:get-active", async (): Promise<ExpTable | null> => {
+    const store = await loadExpTables();
+    return store.tables.find((table) => table.id === store.activeTableId) ?? null;
+  });
+
+  ipcMain.handle("experiments:list", async (): Promise<ExperimentMarker[]> => {
+    return (await loadExperiments()).markers;
+  });
+
+  ipcMain.handle(
+    "experiments:mark",
+    async (_event, label: string, note?: string): Promise<ExperimentMarker> => {
+      return markExperiment(label, note);
+    }
+  );
+
+  ipcMain.handle("experiments:delete", async (_event, label: string) => {
+    await deleteExperiment(label);
+  });
+
+  ipcMain.handle(
+    "experiments:compare",
+    async (_event, label: string, options: ExperimentCompareOptions): Promise<ExperimentComparison> => {
+      const parsed = experimentCompareOptionsSchema.safeParse(options);
+      if (!parsed.success) {
+        throw new Error(
+          `Invalid comparison options: ${parsed.error.errors.map((err) => err.message).join("; ")}`
+        );
+      }
+      const marker = (await loadExperiments()).markers.find(
+        (candidate) => candidate.label.toLowerCase() === label.trim().toLowerCase()
+      );
+      if (!marker) {
+        throw new Error(`No experiment labelled "${label}".`);
+      }
+      return compareExperiment(marker, await loadEventLog(), parsed.data);
+    }
+  );
+
+  ipcMain.handle("alerts:load", async (): Promise<AlertRulesStore> => {
+    return loadAlertRules();
+  });
+
+  ipcMain.handle("alerts:save", async (_event, store: AlertRulesStore) => {
+    await saveAlertRules(store);
+  });
+
+  ipcMain.handle("caps:load", async (): Promise<DailyCapsStore> => {
+    return loadDailyCaps();
+  });
+
+  ipcMain.handle("caps:save", async (_event, store: DailyCapsStore) => {
+    await saveDailyCaps(store);
+  });
+
+  ipcMain.handle("caps:status", async (): Promise<DailyCapStatus[]> => {
+    return getDailyCapStatuses();
+  });
+
+  ipcMain.handle(
+    "counters:create",
+    async (_event, input: CounterCreateInput): Promise<CounterStatus> => {
+      return getCounterStatus(await createCounter(input));
+    }
+  );
+
+  ipcMain.handle(
+    "counters:increment",
+    async (_event, counterId: string, delta: number): Promise<CounterStatus> => {
+      if (!Number.isFinite(delta)) {
+        throw new Error("Counter delta must be a number.");
+      }
+      return updateCounterAndNotify(counterId, (counter) => applyCounterDelta(counter, delta));
+    }
+  );
+
+  ipcMain.handle(
+    "counters:apply-input",
+    async (_event, counterId: string, input: string): Promise<CounterStatus> => {
+      const settings = await actionContext.getSettings();
+      const entry = parseQuickEntry(input, settings.numberLocale);
+      if (!entry) {
+        throw new Error(`Could not read "${input}". Try "+5", "-0.42%", "+1.2m" or "=120".`);
+      }
+      return updateCounterAndNotify(counterId, (counter) =>
+        entry.mode === "relative"
+          ? applyCounterDelta(counter, entry.value)
+          : setCounterValue(counter, entry.value)
+      );
+    }
+  );
+
+  ipcMain.handle("counters:reset", async (_event, counterId: string): Promise<CounterStatus> => {
+    return updateCounterAndNotify(counterId, resetCounterValue);
+  });
+
+  ipcMain.handle("counters:delete", async (_event, counterId: string) => {
+    await deleteCounter(counterId);
+  });
+
   ipcMain.handle("capture:list-sources", async (): Promise<CaptureSource[]> => {
     return listCaptureSources();
   });
 
+  ipcMain.handle(
+    "capture:monitor",
+    async (_event, monitorId: string): Promise<CaptureImageResult> => {
+      return captureMonitorImage(String(monitorId));
+    }
+  );
+
+  ipcMain.handle(
+    "capture:region",
+    async (_event, monitorId: string, region: CaptureRegion): Promise<CaptureImageResult> => {
+      if (!region || region.width <= 0 || region.height <= 0) {
+        throw new Error("Capture region must have a positive size.");
+      }
+      return captureMonitorImage(String(monitorId), region);
+    }
+  );
+
+  ipcMain.handle(
+    "capture:event-screenshot",
+    async (_event, monitorId: string, label: string, region?: CaptureRegion): Promise<string> => {
+      return captureEventScreenshot(String(monitorId), String(label), region);
+    }
+  );
+
   ipcMain.handle(
     "capture:snapshot",
     async (_event, target: CaptureTarget | null): Promise<CaptureSnapshotResult> => {
@@ -924,7 +2027,24 @@ const registerIpc = () => {
     }
   );
 
+  ipcMain.handle("capture:get-status", async (): Promise<CaptureStatus> => {
+    return actionContext.getCaptureStatus();
+  });
+
+  ipcMain.handle(
+    "capture:pause",
+    async (_event, reason?: string): Promise<CaptureStatus> => setCapturePaused(true, reason)
+  );
+
+  ipcMain.handle("capture:resume", async (): Promise<CaptureStatus> => setCapturePaused(false));
+
   ipcMain.handle("capture:request", async (_event, target: CaptureTarget | null): Promise<OcrResult> => {
+    if (shutdownState !== "running") {
+      throw new Error("Overlay is shutting down.");
+    }
+    if (capturePause) {
+      throw new Error("Capture is paused.");
+    }
     if (!target) {
       throw new Error("Capture target missing.");
     }
@@ -991,6 +2111,7 @@ const registerIpc = () => {
           preview,
           length: trimmed.length
         });
+        await reportCaptureResult(trimmed.length > 0);
         return {
           text: ocr.text,
           confidence: ocr.confidence,
@@ -1003,6 +2124,7 @@ const registerIpc = () => {
           capturePath,
           error: formatError(error)
         });
+        await reportCaptureResult(false);
         return {
           text: "",
           confidence: null,
@@ -1017,6 +2139,7 @@ const registerIpc = () => {
         target,
         error: formatError(error)
       });
+      await reportCaptureResult(false);
       throw error;
     }
   });
@@ -1024,9 +2147,77 @@ const registerIpc = () => {
   // capture:process removed (capture handled in main via capture:request).
 };
 
+// Deep links arrive as argv of a second launch on Windows/Linux and as
+// open-url on macOS; either way the running instance stages the import.
+// Dev builds skip the lock so they can run next to an installed copy.
+if (app.isPackaged && !app.requestSingleInstanceLock()) {
+  app.exit(0);
+}
+
+// Unpackaged runs start as `electron <script>`, so the protocol handler has
+// to relaunch Electron with the entry script rather than the bare binary.
+const registerProfileProtocol = () => {
+  if (app.isPackaged) {
+    app.setAsDefaultProtocolClient(PROFILE_PROTOCOL);
+  } else if (process.argv[1]) {
+    app.setAsDefaultProtocolClient(PROFILE_PROTOCOL, process.execPath, [
+      resolvePath(process.argv[1])
+    ]);
+  }
+};
+
+app.on("second-instance", (_event, argv) => {
+  if (overlayWindow && !overlayWindow.isDestroyed()) {
+    overlayWindow.show();
+    overlayWindow.focus();
+  }
+  const link = findDeepLink(argv);
+  if (link) {
+    handleDeepLink(link);
+  }
+});
+
+app.on("open-url", (event, link) => {
+  event.preventDefault();
+  if (app.isReady()) {
+    handleDeepLink(link);
+  } else {
+    app.once("ready", () => handleDeepLink(link));
+  }
+});
+
 app.on("ready", async () => {
+  registerProfileProtocol();
   registerIpc();
   await createOverlayWindow();
+  const launchLink = findDeepLink(process.argv);
+  if (launchLink) {
+    handleDeepLink(launchLink);
+  }
+  screen.on("display-removed", (_event, display) => closeMonitorWindow(display.id));
+  await applyHotkeys();
+  const getLanguage = async () => (await actionContext.getSettings()).language;
+  startAlertEngine(notificationRouter, getLanguage);
+  startDailyCapMonitor(notificationRouter, getLanguage);
+  await startTimerEngine((event) => sendToOverlay("timers:expired", event), getLanguage);
+  startServerClock(async () => (await actionContext.getSettings()).serverClock);
+  configurePingMonitor((await actionContext.getSettings()).pingMonitor, publishPingStats);
+  configureHttpApi((await actionContext.getSettings()).httpApi, httpApiHandlers);
+  configureSpectator((await actionContext.getSettings()).spectator, publishSpectatorSnapshot);
+  await configureLogTail((await actionContext.getSettings()).logTail, publishLogTailLines);
+  startSystemStats((stats) => sendToOverlay("system:stats", stats), actionContext.getSettings);
+  startRetention(runRetention);
+  checkCompatibility(await getLanguage())
+    .then((report) =>
+      report.findings.length > 0 ? logInfo("compatibility.findings", report) : undefined
+    )
+    .catch((error: unknown) =>
+      logError("compatibility.check.failed", { error: formatError(error) })
+    );
+  startVisibilityEngine({
+    getSettings: actionContext.getSettings,
+    publish: (state) => sendToOverlay("widgets:visibility-changed", state)
+  });
 });
 
 app.on("window-all-closed", () => {
@@ -1035,7 +2226,61 @@ app.on("window-all-closed", () => {
   }
 });
 
+const stopBackgroundTasks = () => {
+  stopAlertEngine();
+  stopDailyCapMonitor();
+  stopTimerEngine();
+  stopVisibilityEngine();
+  stopServerClock();
+  stopPingMonitor();
+  stopHttpApi();
+  stopRetention();
+  stopSpectator();
+  stopSystemStats();
+  stopLogTail();
+  stopOverlayState();
+  stopCaptureTicker();
+};
+
+const waitForRendererFlush = () =>
+  new Promise<void>((resolve) => {
+    if (!overlayWindow || overlayWindow.isDestroyed()) {
+      resolve();
+      return;
+    }
+    const finish = () => {
+      clearTimeout(timeout);
+      ipcMain.removeListener("app:shutdown-ready", finish);
+      resolve();
+    };
+    const timeout = setTimeout(finish, SHUTDOWN_FLUSH_TIMEOUT_MS);
+    ipcMain.once("app:shutdown-ready", finish);
+    sendToOverlay("app:before-quit");
+  });
+
+app.on("before-quit", (event) => {
+  if (shutdownState === "done") {
+    return;
+  }
+  event.preventDefault();
+  if (shutdownState === "flushing") {
+    return;
+  }
+  shutdownState = "flushing";
+  stopBackgroundTasks();
+  waitForRendererFlush()
+    .then(() => flushPendingWrites())
+    .then(() => logInfo("app.shutdown.flushed"))
+    .catch(() => undefined)
+    .finally(() => {
+      shutdownState = "done";
+      app.quit();
+    });
+});
+
 app.on("will-quit", () => {
   globalShortcut.unregister(escapeShortcut);
+  unregisterHotkeys();
+  stopBackgroundTasks();
   shutdownOcrWorker().catch(() => undefined);
 });
@@ -0,0 +1,149 @@
+import { Notification } from "electron";
+import {
+  AlertAction,
+  AlertEvent,
+  AlertNotification,
+  DoNotDisturbStatus,
+  OverlaySettings,
+  QuietHoursSettings,
+  QuietHoursWindow
+} from "../shared/ipc";
+import { logError } from "./logging";
+
+const WEBHOOK_TIMEOUT_MS = 5000;
+const NOTIFICATION_TITLE = "Overlay MMO";
+
+type SendToOverlay = (channel: string, payload?: unknown) => void;
+type CaptureScreenshot = (
+  action: Extract<AlertAction, { type: "screenshot" }>,
+  event: AlertEvent
+) => Promise<string>;
+
+const parseClockMinutes = (value: string): number | null => {
+  const match = /^(\d{1,2}):(\d{2})$/.exec(value.trim());
+  if (!match) {
+    return null;
+  }
+  const hours = Number(match[1]);
+  const minutes = Number(match[2]);
+  return hours < 24 && minutes < 60 ? hours * 60 + minutes : null;
+};
+
+// Windows are in local time and may cross midnight (22:00-07:00); `days`
+// lists the weekdays (0 = Sunday) on which a window starts.
+const isInWindow = (range: QuietHoursWindow, date: Date) => {
+  const start = parseClockMinutes(range.start);
+  const end = parseClockMinutes(range.end);
+  if (start === null || end === null || start === end) {
+    return false;
+  }
+  const minutes = date.getHours() * 60 + date.getMinutes();
+  const day = date.getDay();
+  if (start < end) {
+    return range.days.includes(day) && minutes >= start && minutes < end;
+  }
+  const previousDay = (day + 6) % 7;
+  return (
+    (range.days.includes(day) && minutes >= start) ||
+    (range.days.includes(previousDay) && minutes < end)
+  );
+};
+
+export const isWithinQuietHours = (settings: QuietHoursSettings, now = Date.now()) =>
+  settings.enabled && settings.windows.some((range) => isInWindow(range, new Date(now)));
+
+// An expired `until` switches DND off without rewriting settings.
+export const getDoNotDisturbStatus = (
+  settings: OverlaySettings,
+  now = Date.now()
+): DoNotDisturbStatus => {
+  const { enabled, until } = settings.doNotDisturb;
+  return {
+    enabled,
+    until,
+    active: enabled && (until === null || until > now),
+    quietHoursActive: isWithinQuietHours(settings.quietHours, now)
+  };
+};
+
+const postWebhook = async (url: string, event: AlertEvent) => {
+  const controller = new AbortController();
+  const timeout = setTimeout(() => controller.abort(), WEBHOOK_TIMEOUT_MS);
+  try {
+    const response = await fetch(url, {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(event),
+      signal: controller.signal
+    });
+    if (!response.ok) {
+      throw new Error(`Webhook responded ${response.status}`);
+    }
+  } finally {
+    clearTimeout(timeout);
+  }
+};
+
+const showToast = (event: AlertEvent) => {
+  if (!Notification.isSupported()) {
+    return;
+  }
+  new Notification({
+    title: NOTIFICATION_TITLE,
+    body: event.message,
+    urgency: event.critical ? "critical" : "normal"
+  }).show();
+};
+
+// Single delivery point for alerts: the overlay always receives the event so
+// it can be logged, and each configured action fans out from here. Do not
+// disturb silences toasts and sounds for non-critical alerts only; quiet
+// hours downgrade them to the OS notification center. A screenshot is taken
+// first so its path travels with the event into the log and webhooks.
+export const createNotificationRouter = (
+  sendToOverlay: SendToOverlay,
+  getSettings: () => Promise<OverlaySettings>,
+  captureScreenshot?: CaptureScreenshot
+) => ({
+  deliver: async (firedEvent: AlertEvent, actions: AlertAction[]) => {
+    let event = firedEvent;
+    const screenshot = actions.find(
+      (action): action is Extract<AlertAction, { type: "screenshot" }> =>
+        action.type === "screenshot"
+    );
+    if (screenshot && captureScreenshot) {
+      try {
+        event = { ...event, screenshotPath: await captureScreenshot(screenshot, event) };
+      } catch (error) {
+        await logError("alerts.screenshot.failed", {
+          ruleId: event.ruleId,
+          error: error instanceof Error ? error.message : String(error)
+        });
+      }
+    }
+    const settings = await getSettings();
+    const status = getDoNotDisturbStatus(settings, event.firedAt);
+    const silenced = !event.critical && status.active;
+    const quiet = !event.critical && status.quietHoursActive;
+    const notification: AlertNotification = {
+      event,
+      sound: !silenced && !quiet && actions.some((action) => action.type === "sound")
+    };
+    sendToOverlay("alerts:fired", notification);
+    for (const action of actions) {
+      if (action.type === "toast" && !silenced) {
+        showToast(event);
+      }
+      if (action.type === "webhook" && !quiet) {
+        await postWebhook(action.url, event).catch((error: unknown) =>
+          logError("alerts.webhook.failed", {
+            ruleId: event.ruleId,
+            error: error instanceof Error ? error.message : String(error)
+          })
+        );
+      }
+    }
+  }
+});
+
+export type NotificationRouter = ReturnType<typeof createNotificationRouter>;
@@ -0,0 +1,170 @@
+import { WebContents } from "electron";
+import {
+  OverlayRefreshSettings,
+  OverlaySettings,
+  OverlaySnapshot,
+  OverlayStateDelta
+} from "../shared/ipc";
+import { logError } from "./logging";
+import { getDoNotDisturbStatus } from "./notifications";
+import { getPingStats } from "./pingMonitor";
+import { getServerTime } from "./serverClock";
+import { listCounters, listTimers } from "./storage";
+import { getWidgetVisibility } from "./visibility";
+
+const OVERLAY_STATE_TICK_MS = 250;
+
+type SnapshotSection = Exclude<keyof OverlaySnapshot, "capturedAt">;
+
+type SectionBuilder<K extends SnapshotSection> = (
+  settings: OverlaySettings
+) => Promise<OverlaySnapshot[K]> | OverlaySnapshot[K];
+
+const sectionBuilders: { [K in SnapshotSection]: SectionBuilder<K> } = {
+  timers: () => listTimers(),
+  counters: () => listCounters(),
+  widgetVisibility: () => getWidgetVisibility(),
+  doNotDisturb: (settings) => getDoNotDisturbStatus(settings),
+  serverTime: (settings) => getServerTime(settings.serverClock),
+  ping: () => getPingStats()
+};
+
+const snapshotSections = Object.keys(sectionBuilders) as SnapshotSection[];
+
+const sectionCadence = (refresh: OverlayRefreshSettings, section: SnapshotSection) => {
+  switch (section) {
+    case "timers":
+    case "serverTime":
+      return refresh.timersMs;
+    case "counters":
+      return refresh.countersMs;
+    case "ping":
+      return refresh.pingMs;
+    default:
+      return refresh.statusMs;
+  }
+};
+
+// Everything the overlay needs for one render pass, gathered in a single
+// round-trip instead of one IPC call per widget.
+export const buildOverlaySnapshot = async (
+  settings: OverlaySettings
+): Promise<OverlaySnapshot> => {
+  const entries = await Promise.all(
+    snapshotSections.map(
+      async (section) => [section, await sectionBuilders[section](settings)] as const
+    )
+  );
+  return { capturedAt: Date.now(), ...Object.fromEntries(entries) } as OverlaySnapshot;
+};
+
+const subscribers = new Map<number, WebContents>();
+let lastSerialized: Partial<Record<SnapshotSection, string>> = {};
+let lastRefreshedAt: Partial<Record<SnapshotSection, number>> = {};
+let stateTimer: NodeJS.Timeout | null = null;
+let tickInFlight = false;
+
+// The server clock counts as changed once per whole second, which gives the
+// overlay a backend-driven clock for running stopwatches and countdowns.
+const serializeSection = (section: SnapshotSection, value: unknown) => {
+  if (section !== "serverTime") {
+    return JSON.stringify(value);
+  }
+  const serverTime = value as OverlaySnapshot["serverTime"];
+  return JSON.stringify({ ...serverTime, serverTime: Math.floor(serverTime.serverTime / 1000) });
+};
+
+const rememberSnapshot = (snapshot: OverlaySnapshot) => {
+  lastSerialized = Object.fromEntries(
+    snapshotSections.map((section) => [section, serializeSection(section, snapshot[section])])
+  );
+  lastRefreshedAt = Object.fromEntries(
+    snapshotSections.map((section) => [section, snapshot.capturedAt])
+  );
+};
+
+// Each section is rebuilt on its own cadence; only sections that are due and
+// whose content changed end up in the delta.
+const collectDueChanges = async (
+  settings: OverlaySettings,
+  now: number
+): Promise<OverlayStateDelta | null> => {
+  const due = snapshotSections.filter(
+    (section) =>
+      now - (lastRefreshedAt[section] ?? 0) >= sectionCadence(settings.overlayRefresh, section)
+  );
+  const changes: Partial<OverlaySnapshot> = {};
+  for (const section of due) {
+    const value = await sectionBuilders[section](settings);
+    lastRefreshedAt[section] = now;
+    const serialized = serializeSection(section, value);
+    if (lastSerialized[section] !== serialized) {
+      lastSerialized[section] = serialized;
+      Object.assign(changes, { [section]: value });
+    }
+  }
+  return Object.keys(changes).length > 0 ? { capturedAt: now, changes } : null;
+};
+
+const stopOverlayStateTicker = () => {
+  if (stateTimer) {
+    clearInterval(stateTimer);
+    stateTimer = null;
+  }
+};
+
+export const unsubscribeOverlayState = (contentsId: number) => {
+  subscribers.delete(contentsId);
+  if (subscribers.size === 0) {
+    stopOverlayStateTicker();
+  }
+};
+
+// Subscribers get a full snapshot up front and then only changed sections
+// through "overlay:state-delta".
+export const subscribeOverlayState = async (
+  contents: WebContents,
+  getSettings: () => Promise<OverlaySettings>
+): Promise<OverlaySnapshot> => {
+  const snapshot = await buildOverlaySnapshot(await getSettings());
+  if (!subscribers.has(contents.id)) {
+    subscribers.set(contents.id, contents);
+    contents.once("destroyed", () => unsubscribeOverlayState(contents.id));
+  }
+  if (stateTimer) {
+    return snapshot;
+  }
+  rememberSnapshot(snapshot);
+  stateTimer = setInterval(() => {
+    if (tickInFlight) {
+      return;
+    }
+    tickInFlight = true;
+    getSettings()
+      .then((settings) => collectDueChanges(settings, Date.now()))
+      .then((delta) => {
+        if (!delta) {
+          return;
+        }
+        subscribers.forEach((subscriber) => {
+          if (!subscriber.isDestroyed()) {
+            subscriber.send("overlay:state-delta", delta);
+          }
+        });
+      })
+      .catch((error: unknown) =>
+        logError("overlay.state.tick.failed", {
+          error: error instanceof Error ? error.message : String(error)
+        })
+      )
+      .finally(() => {
+        tickInFlight = false;
+      });
+  }, OVERLAY_STATE_TICK_MS);
+  return snapshot;
+};
+
+export const stopOverlayState = () => {
+  subscribers.clear();
+  stopOverlayStateTicker();
+};
@@ -0,0 +1,119 @@
+import { Socket } from "net";
+import { PingMonitorSettings, PingSample, PingStats } from "../shared/ipc";
+import { logError } from "./logging";
+import { appendPingSamples } from "./storage";
+
+const PING_TIMEOUT_MS = 2000;
+const PING_SAMPLE_LIMIT = 720;
+const PING_STATS_WINDOW = 20;
+
+type PingPublisher = (stats: PingStats) => void;
+
+let samples: PingSample[] = [];
+let activeSettings: PingMonitorSettings | null = null;
+let pingTimer: NodeJS.Timeout | null = null;
+let tickInFlight = false;
+
+// ICMP needs elevated privileges on most platforms, so latency is measured as
+// the time to complete a TCP handshake with the game server.
+const measureTcpConnect = (host: string, port: number): Promise<number | null> =>
+  new Promise((resolve) => {
+    const socket = new Socket();
+    const startedAt = process.hrtime.bigint();
+    const finish = (latencyMs: number | null) => {
+      socket.destroy();
+      resolve(latencyMs);
+    };
+    socket.setTimeout(PING_TIMEOUT_MS);
+    socket.once("connect", () => {
+      finish(Number(process.hrtime.bigint() - startedAt) / 1e6);
+    });
+    socket.once("timeout", () => finish(null));
+    socket.once("error", () => finish(null));
+    socket.connect(port, host);
+  });
+
+export const getPingStats = (): PingStats => {
+  const recent = samples.slice(-PING_STATS_WINDOW);
+  const latencies = recent
+    .map((sample) => sample.latencyMs)
+    .filter((value): value is number => value !== null);
+  const averageMs =
+    latencies.length > 0
+      ? latencies.reduce((sum, value) => sum + value, 0) / latencies.length
+      : null;
+  const jitterMs =
+    latencies.length > 1
+      ? latencies
+          .slice(1)
+          .reduce((sum, value, index) => sum + Math.abs(value - latencies[index]), 0) /
+        (latencies.length - 1)
+      : null;
+  return {
+    host: activeSettings?.host ?? "",
+    port: activeSettings?.port ?? 0,
+    currentMs: recent.length > 0 ? recent[recent.length - 1].latencyMs : null,
+    averageMs,
+    jitterMs,
+    lossPercent:
+      recent.length > 0 ? ((recent.length - latencies.length) / recent.length) * 100 : 0,
+    samples
+  };
+};
+
+// The in-memory window feeds the live stats; every sample is also appended to
+// the profile's ping history for reviewing lag after the session.
+const sampleOnce = async (settings: PingMonitorSettings, publish: PingPublisher) => {
+  const latencyMs = await measureTcpConnect(settings.host, settings.port);
+  const sample = { at: Date.now(), latencyMs };
+  samples = [...samples, sample].slice(-PING_SAMPLE_LIMIT);
+  publish(getPingStats());
+  await appendPingSamples([{ ...sample, host: settings.host, port: settings.port }]);
+};
+
+export const stopPingMonitor = () => {
+  if (pingTimer) {
+    clearInterval(pingTimer);
+    pingTimer = null;
+  }
+};
+
+const sameSettings = (a: PingMonitorSettings | null, b: PingMonitorSettings) =>
+  !!a &&
+  a.enabled === b.enabled &&
+  a.host === b.host &&
+  a.port === b.port &&
+  a.intervalSeconds === b.intervalSeconds;
+
+// Called on startup and whenever settings change; restarts the pinger only
+// when the target or interval actually changed.
+export const configurePingMonitor = (settings: PingMonitorSettings, publish: PingPublisher) => {
+  if (sameSettings(activeSettings, settings)) {
+    return;
+  }
+  const targetChanged =
+    activeSettings?.host !== settings.host || activeSettings?.port !== settings.port;
+  stopPingMonitor();
+  if (targetChanged) {
+    samples = [];
+  }
+  activeSettings = settings;
+  if (!settings.enabled || !settings.host.trim()) {
+    return;
+  }
+  pingTimer = setInterval(() => {
+    if (tickInFlight) {
+      return;
+    }
+    tickInFlight = true;
+    sampleOnce(settings, publish)
+      .catch((error: unknown) =>
+        logError("ping.tick.failed", {
+          error: error instanceof Error ? error.message : String(error)
+        })
+      )
+      .finally(() => {
+        tickInFlight = false;
+      });
+  }, Math.max(1, settings.intervalSeconds) * 1000);
+};
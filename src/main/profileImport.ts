@@ -0,0 +1,95 @@
+import { ProfileImportSummary, RulesStore } from "../shared/ipc";
+import { profileBundleSchema } from "../shared/profileBundleSchema";
+import { validateWidgetSpec, WidgetSpec } from "../widgetSpec";
+import { logInfo } from "./logging";
+
+export const PROFILE_PROTOCOL = "overlaymmo";
+
+const IMPORT_HOST = "import-profile";
+const DOWNLOAD_TIMEOUT_MS = 10000;
+const BUNDLE_SIZE_LIMIT_BYTES = 1024 * 1024;
+
+type StagedImport = {
+  summary: ProfileImportSummary;
+  plan: WidgetSpec;
+  rules?: RulesStore;
+};
+
+let staged: StagedImport | null = null;
+
+export const findDeepLink = (argv: string[]) =>
+  argv.find((arg) => arg.toLowerCase().startsWith(`${PROFILE_PROTOCOL}://`)) ?? null;
+
+// Only overlaymmo://import-profile?url=https://... is accepted; plain http
+// and other hosts are rejected before anything is downloaded.
+const resolveBundleUrl = (link: string) => {
+  const parsed = new URL(link);
+  if (parsed.protocol !== `${PROFILE_PROTOCOL}:` || parsed.hostname !== IMPORT_HOST) {
+    throw new Error(`Unsupported link: ${link}`);
+  }
+  const source = parsed.searchParams.get("url");
+  if (!source) {
+    throw new Error("Import link is missing a url parameter.");
+  }
+  const bundleUrl = new URL(source);
+  if (bundleUrl.protocol !== "https:") {
+    throw new Error("Profile bundles must be served over https.");
+  }
+  return bundleUrl;
+};
+
+const downloadBundle = async (url: URL): Promise<unknown> => {
+  const controller = new AbortController();
+  const timeout = setTimeout(() => controller.abort(), DOWNLOAD_TIMEOUT_MS);
+  try {
+    const response = await fetch(url, { signal: controller.signal });
+    if (!response.ok) {
+      throw new Error(`Bundle download responded ${response.status}`);
+    }
+    const body = await response.text();
+    if (Buffer.byteLength(body, "utf8") > BUNDLE_SIZE_LIMIT_BYTES) {
+      throw new Error("Profile bundle is larger than 1 MB.");
+    }
+    return JSON.parse(body);
+  } finally {
+    clearTimeout(timeout);
+  }
+};
+
+// Downloads and validates a shared bundle but writes nothing: the bundle
+// waits in memory until the user applies or discards it.
+export const stageProfileImport = async (link: string): Promise<ProfileImportSummary> => {
+  const bundleUrl = resolveBundleUrl(link);
+  const bundle = profileBundleSchema.safeParse(await downloadBundle(bundleUrl));
+  if (!bundle.success) {
+    throw new Error(
+      `Invalid profile bundle: ${bundle.error.errors.map((err) => err.message).join("; ")}`
+    );
+  }
+  const plan = validateWidgetSpec(bundle.data.plan);
+  if (!plan.ok) {
+    throw new Error(`Invalid profile plan: ${plan.error}`);
+  }
+  const summary: ProfileImportSummary = {
+    name: bundle.data.name,
+    sourceUrl: bundleUrl.toString(),
+    stagedAt: Date.now(),
+    widgetCount: plan.value.widgets.length,
+    ruleCount: bundle.data.rules?.rules.length ?? 0
+  };
+  staged = { summary, plan: plan.value, rules: bundle.data.rules as RulesStore | undefined };
+  await logInfo("profile.import.staged", summary);
+  return summary;
+};
+
+export const getStagedProfileImport = () => staged?.summary ?? null;
+
+export const takeStagedProfileImport = () => {
+  const current = staged;
+  staged = null;
+  return current;
+};
+
+export const discardStagedProfileImport = () => {
+  staged = null;
+};
@@ -0,0 +1,88 @@
+import { CounterEvent, EventLog, RetentionSettings } from "../shared/ipc";
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+const NIGHTLY_HOUR = 3;
+const CHECK_INTERVAL_MS = 60 * 60 * 1000;
+
+let retentionTimer: NodeJS.Timeout | null = null;
+let lastRunDay: string | null = null;
+
+const cutoffFor = (settings: RetentionSettings, now: number) => now - settings.rawDays * DAY_MS;
+
+// Old entries keep their type and timestamp, so rate widgets and alerts still
+// count them, but drop the OCR text and capture paths that make up most of
+// the file.
+export const compactEventLog = (
+  log: EventLog,
+  settings: RetentionSettings,
+  now = Date.now()
+): { log: EventLog; compacted: number } => {
+  if (!settings.enabled) {
+    return { log, compacted: 0 };
+  }
+  const cutoff = cutoffFor(settings, now);
+  let compacted = 0;
+  const entries = log.entries.map((entry) => {
+    if (entry.timestamp >= cutoff || !entry.data) {
+      return entry;
+    }
+    compacted += 1;
+    const compactedEntry = { ...entry };
+    delete compactedEntry.data;
+    return compactedEntry;
+  });
+  return { log: compacted > 0 ? { ...log, entries } : log, compacted };
+};
+
+// Events older than the raw window collapse to one per counter per bucket:
+// the bucket's final value with the deltas summed.
+export const downsampleCounterEvents = (
+  events: CounterEvent[],
+  settings: RetentionSettings,
+  now = Date.now()
+): CounterEvent[] => {
+  if (!settings.enabled) {
+    return events;
+  }
+  const cutoff = cutoffFor(settings, now);
+  const bucketMs = settings.rollupMinutes * 60 * 1000;
+  const buckets = new Map<string, CounterEvent>();
+  const recent: CounterEvent[] = [];
+  events.forEach((event) => {
+    if (event.timestamp >= cutoff) {
+      recent.push(event);
+      return;
+    }
+    const bucketStart = Math.floor(event.timestamp / bucketMs) * bucketMs;
+    const key = `${event.counterId}:${bucketStart}`;
+    const existing = buckets.get(key);
+    buckets.set(key, {
+      counterId: event.counterId,
+      value: event.value,
+      delta: (existing?.delta ?? 0) + event.delta,
+      timestamp: Math.max(existing?.timestamp ?? 0, event.timestamp)
+    });
+  });
+  return [...buckets.values(), ...recent].sort((a, b) => a.timestamp - b.timestamp);
+};
+
+export const stopRetention = () => {
+  if (retentionTimer) {
+    clearInterval(retentionTimer);
+    retentionTimer = null;
+  }
+};
+
+// Checks hourly and runs once per day after 03:00 local time.
+export const startRetention = (run: () => Promise<unknown>) => {
+  stopRetention();
+  retentionTimer = setInterval(() => {
+    const now = new Date();
+    const day = now.toDateString();
+    if (now.getHours() < NIGHTLY_HOUR || lastRunDay === day) {
+      return;
+    }
+    lastRunDay = day;
+    run().catch(() => undefined);
+  }, CHECK_INTERVAL_MS);
+};
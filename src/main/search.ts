@@ -0,0 +1,123 @@
+import { GlobalSearchKind, GlobalSearchResult } from "../shared/ipc";
+import { flattenWidgets } from "../widgetSpec";
+import {
+  loadCounters,
+  loadMemory,
+  loadPlan,
+  loadRules,
+  loadSettings,
+  loadTimers
+} from "./storage";
+
+const GLOBAL_SEARCH_LIMIT = 50;
+const TITLE_MATCH_BONUS = 2;
+// Credentials never surface in search, whatever settings group they live in.
+const SECRET_SETTING_KEY = /(token|apikey|secret)$/i;
+
+type Candidate = {
+  kind: GlobalSearchKind;
+  id: string;
+  title: string;
+  detail?: string;
+};
+
+const tokenize = (query: string): string[] =>
+  query
+    .toLowerCase()
+    .split(/\s+/)
+    .filter(Boolean);
+
+const scoreCandidate = (candidate: Candidate, terms: string[]): number | null => {
+  const title = candidate.title.toLowerCase();
+  const detail = (candidate.detail ?? "").toLowerCase();
+  let score = 0;
+  for (const term of terms) {
+    const inTitle = title.includes(term);
+    if (!inTitle && !detail.includes(term) && !candidate.id.toLowerCase().includes(term)) {
+      return null;
+    }
+    score += inTitle ? TITLE_MATCH_BONUS : 1;
+  }
+  return score;
+};
+
+const flattenSettings = (value: unknown, prefix = ""): Array<[string, string]> => {
+  if (value === null || typeof value !== "object") {
+    return [[prefix, String(value)]];
+  }
+  return Object.entries(value as Record<string, unknown>).flatMap(([key, child]) =>
+    flattenSettings(child, prefix ? `${prefix}.${key}` : key)
+  );
+};
+
+const collectCandidates = async (): Promise<Candidate[]> => {
+  const [memory, timers, counters, planResult, rules, settings] = await Promise.all([
+    loadMemory(),
+    loadTimers(),
+    loadCounters(),
+    loadPlan(),
+    loadRules(),
+    loadSettings()
+  ]);
+  const candidates: Candidate[] = [];
+  memory.entries.forEach((entry) => {
+    if (entry.type === "note") {
+      candidates.push({
+        kind: "note",
+        id: entry.id,
+        title: entry.payload.text.split("\n")[0] ?? "",
+        detail: [entry.payload.text, ...(entry.tags ?? [])].join(" ")
+      });
+    }
+  });
+  timers.timers.forEach((timer) => {
+    candidates.push({ kind: "timer", id: timer.id, title: timer.label, detail: timer.category });
+  });
+  counters.counters.forEach((counter) => {
+    candidates.push({
+      kind: "counter",
+      id: counter.id,
+      title: counter.label,
+      detail: String(counter.value)
+    });
+  });
+  flattenWidgets(planResult.plan?.widgets ?? []).forEach((widget) => {
+    candidates.push({
+      kind: "widget",
+      id: widget.id,
+      title: widget.title ?? widget.id,
+      detail: widget.type
+    });
+  });
+  rules.rules.forEach((rule) => {
+    candidates.push({
+      kind: "rule",
+      id: rule.id,
+      title: rule.pattern,
+      detail: `${rule.action.type} ${rule.action.widgetId}`
+    });
+  });
+  flattenSettings(settings).forEach(([key, value]) => {
+    if (SECRET_SETTING_KEY.test(key)) {
+      return;
+    }
+    candidates.push({ kind: "setting", id: key, title: key, detail: value });
+  });
+  return candidates;
+};
+
+export const globalSearch = async (query: string): Promise<GlobalSearchResult[]> => {
+  const terms = tokenize(query);
+  if (terms.length === 0) {
+    return [];
+  }
+  const candidates = await collectCandidates();
+  const results: GlobalSearchResult[] = [];
+  candidates.forEach((candidate) => {
+    const score = scoreCandidate(candidate, terms);
+    if (score !== null) {
+      results.push({ ...candidate, score });
+    }
+  });
+  return results.sort((a, b) => b.score - a.score).slice(0, GLOBAL_SEARCH_LIMIT);
+};
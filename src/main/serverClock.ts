@@ -0,0 +1,113 @@
+import { createSocket } from "dgram";
+import { ServerClockSettings, ServerTimeInfo } from "../shared/ipc";
+import { logError } from "./logging";
+
+const NTP_PORT = 123;
+const NTP_TIMEOUT_MS = 3000;
+const NTP_PACKET_SIZE = 48;
+const NTP_EPOCH_OFFSET_SECONDS = 2208988800;
+const NTP_RESYNC_MS = 30 * 60 * 1000;
+
+type SyncState = {
+  driftMs: number;
+  lastSyncAt: number | null;
+  roundTripMs: number | null;
+  syncError?: string;
+};
+
+let syncState: SyncState = { driftMs: 0, lastSyncAt: null, roundTripMs: null };
+let resyncTimer: NodeJS.Timeout | null = null;
+
+const readNtpTimestamp = (packet: Buffer, offset: number) => {
+  const seconds = packet.readUInt32BE(offset) - NTP_EPOCH_OFFSET_SECONDS;
+  const fraction = packet.readUInt32BE(offset + 4) / 2 ** 32;
+  return (seconds + fraction) * 1000;
+};
+
+// Single SNTP exchange (RFC 4330). Drift is how far the local clock is behind
+// the NTP server; the round trip is reported as a quality hint.
+const queryNtp = (server: string): Promise<{ driftMs: number; roundTripMs: number }> =>
+  new Promise((resolve, reject) => {
+    const socket = createSocket("udp4");
+    const request = Buffer.alloc(NTP_PACKET_SIZE);
+    request[0] = 0x1b;
+    let sentAt = 0;
+    const timeout = setTimeout(() => {
+      socket.close();
+      reject(new Error(`NTP request to ${server} timed out.`));
+    }, NTP_TIMEOUT_MS);
+
+    socket.once("error", (error) => {
+      clearTimeout(timeout);
+      socket.close();
+      reject(error);
+    });
+    socket.once("message", (packet) => {
+      const receivedAt = Date.now();
+      clearTimeout(timeout);
+      socket.close();
+      if (packet.length < NTP_PACKET_SIZE) {
+        reject(new Error("NTP response too short."));
+        return;
+      }
+      const serverReceive = readNtpTimestamp(packet, 32);
+      const serverTransmit = readNtpTimestamp(packet, 40);
+      resolve({
+        driftMs: (serverReceive - sentAt + (serverTransmit - receivedAt)) / 2,
+        roundTripMs: receivedAt - sentAt - (serverTransmit - serverReceive)
+      });
+    });
+    sentAt = Date.now();
+    socket.send(request, NTP_PORT, server);
+  });
+
+export const syncServerClock = async (settings: ServerClockSettings): Promise<SyncState> => {
+  try {
+    const result = await queryNtp(settings.ntpServer);
+    syncState = {
+      driftMs: result.driftMs,
+      roundTripMs: result.roundTripMs,
+      lastSyncAt: Date.now()
+    };
+  } catch (error) {
+    const message = error instanceof Error ? error.message : String(error);
+    syncState = { ...syncState, syncError: message };
+    await logError("clock.ntp.failed", { server: settings.ntpServer, error: message });
+  }
+  return syncState;
+};
+
+export const getServerTime = (settings: ServerClockSettings): ServerTimeInfo => {
+  const driftMs = settings.ntpEnabled ? syncState.driftMs : 0;
+  return {
+    serverTime: Date.now() + driftMs + settings.utcOffsetMinutes * 60 * 1000,
+    utcOffsetMinutes: settings.utcOffsetMinutes,
+    driftMs,
+    lastSyncAt: syncState.lastSyncAt,
+    roundTripMs: syncState.roundTripMs,
+    syncError: syncState.syncError
+  };
+};
+
+export const startServerClock = (getSettings: () => Promise<ServerClockSettings>) => {
+  if (resyncTimer) {
+    return;
+  }
+  const resync = async () => {
+    const settings = await getSettings();
+    if (settings.ntpEnabled) {
+      await syncServerClock(settings);
+    }
+  };
+  resync().catch(() => undefined);
+  resyncTimer = setInterval(() => {
+    resync().catch(() => undefined);
+  }, NTP_RESYNC_MS);
+};
+
+export const stopServerClock = () => {
+  if (resyncTimer) {
+    clearInterval(resyncTimer);
+    resyncTimer = null;
+  }
+};
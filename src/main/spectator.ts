@@ -0,0 +1,141 @@
+import { EventLog, SpectatorSettings, SpectatorSnapshot } from "../shared/ipc";
+import { eventLogSchema } from "../shared/eventLogSchema";
+import { validateWidgetSpec, WidgetSpec } from "../widgetSpec";
+import { logError, logInfo } from "./logging";
+
+const FETCH_TIMEOUT_MS = 5000;
+const MIN_POLL_MS = 500;
+
+type PublishSnapshot = (snapshot: SpectatorSnapshot) => void;
+
+const emptySnapshot: SpectatorSnapshot = {
+  connected: false,
+  lastSyncAt: null,
+  error: null,
+  plan: null,
+  eventLog: null
+};
+
+let activeSettings: SpectatorSettings | null = null;
+let pollTimer: NodeJS.Timeout | null = null;
+let pollInFlight = false;
+let snapshot: SpectatorSnapshot = emptySnapshot;
+let lastPayload = "";
+
+const baseUrl = (url: string) => {
+  const parsed = new URL(url.trim());
+  if (parsed.protocol !== "http:" && parsed.protocol !== "https:") {
+    throw new Error("Spectator URL must be http or https.");
+  }
+  return parsed.toString().replace(/\/+$/, "");
+};
+
+const fetchJson = async (url: string, token: string): Promise<unknown> => {
+  const controller = new AbortController();
+  const timeout = setTimeout(() => controller.abort(), FETCH_TIMEOUT_MS);
+  try {
+    const response = await fetch(url, {
+      headers: token ? { Authorization: `Bearer ${token}` } : {},
+      signal: controller.signal
+    });
+    if (!response.ok) {
+      throw new Error(`${url} responded ${response.status}`);
+    }
+    return await response.json();
+  } finally {
+    clearTimeout(timeout);
+  }
+};
+
+// The primary is another machine on the network, so its payloads are
+// validated like any imported file before they reach the renderer.
+const poll = async (settings: SpectatorSettings, publish: PublishSnapshot) => {
+  const root = baseUrl(settings.url);
+  const [planData, logData] = await Promise.all([
+    fetchJson(`${root}/plan`, settings.token),
+    fetchJson(`${root}/event-log`, settings.token)
+  ]);
+  // A primary that has never saved a plan answers null; that is "nothing to
+  // show yet", not a protocol error.
+  let planValue: WidgetSpec | null = null;
+  if (planData !== null) {
+    const plan = validateWidgetSpec(planData);
+    if (!plan.ok) {
+      throw new Error(`Primary sent an invalid plan: ${plan.error}`);
+    }
+    planValue = plan.value;
+  }
+  const eventLog = eventLogSchema.safeParse(logData);
+  if (!eventLog.success) {
+    throw new Error("Primary sent an invalid event log.");
+  }
+  const payload = JSON.stringify([planValue, eventLog.data]);
+  const reconnected = !snapshot.connected;
+  snapshot = {
+    connected: true,
+    lastSyncAt: Date.now(),
+    error: null,
+    plan: planValue,
+    eventLog: eventLog.data as EventLog
+  };
+  if (reconnected) {
+    await logInfo("spectator.connected", { url: root });
+  }
+  if (reconnected || payload !== lastPayload) {
+    lastPayload = payload;
+    publish(snapshot);
+  }
+};
+
+export const getSpectatorSnapshot = (): SpectatorSnapshot => snapshot;
+
+export const stopSpectator = () => {
+  if (pollTimer) {
+    clearInterval(pollTimer);
+    pollTimer = null;
+  }
+  activeSettings = null;
+  snapshot = emptySnapshot;
+  lastPayload = "";
+};
+
+const sameSettings = (a: SpectatorSettings | null, b: SpectatorSettings) =>
+  !!a &&
+  a.enabled === b.enabled &&
+  a.url === b.url &&
+  a.token === b.token &&
+  a.pollMs === b.pollMs;
+
+// The last good plan stays on screen while the primary is unreachable; only
+// the connection state changes, and the failure is logged once per outage.
+export const configureSpectator = (settings: SpectatorSettings, publish: PublishSnapshot) => {
+  if (sameSettings(activeSettings, settings)) {
+    return;
+  }
+  stopSpectator();
+  activeSettings = settings;
+  if (!settings.enabled || !settings.url.trim()) {
+    return;
+  }
+  const tick = () => {
+    if (pollInFlight) {
+      return;
+    }
+    pollInFlight = true;
+    poll(settings, publish)
+      .catch((error: unknown) => {
+        const message = error instanceof Error ? error.message : String(error);
+        if (!snapshot.connected && snapshot.error === message) {
+          return undefined;
+        }
+        snapshot = { ...snapshot, connected: false, error: message };
+        publish(snapshot);
+        return logError("spectator.poll.failed", { error: message });
+      })
+      .finally(() => {
+        pollInFlight = false;
+      });
+  };
+  tick();
+  pollTimer = setInterval(tick, Math.max(MIN_POLL_MS, settings.pollMs));
+};
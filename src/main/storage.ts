@@ -1,19 +1,60 @@
 import { app } from "electron";
 import { promises as fs } from "fs";
-import { join } from "path";
+import { isAbsolute, join } from "path";
 import { z } from "zod";
 import {
+  AlertRulesStore,
+  CounterCreateInput,
+  CounterEvent,
+  CounterHistoryStore,
+  CounterRecord,
+  CounterStatus,
+  CountersStore,
+  DailyCapsStore,
   EventLog,
+  ExperimentMarker,
+  ExperimentsStore,
+  ExpTable,
+  ExpTablesStore,
+  HotkeyBinding,
+  HotkeysStore,
   MemoryEntry,
   MemoryStore,
+  NoteEntry,
+  NoteSearchHit,
+  NoteUpdate,
   OverlaySettings,
+  PingHistorySample,
+  PingHistoryStore,
   PlanSaveMeta,
   PlanLoadResult,
-  RulesStore
+  ProfileBackupResult,
+  RetentionReport,
+  RetentionSettings,
+  RulesStore,
+  TimerCreateInput,
+  TimerGroup,
+  TimerListOptions,
+  TimerListResult,
+  TimerRecord,
+  TimersStore,
+  TimerUpdate
 } from "../shared/ipc";
+import { alertRulesStoreSchema } from "../shared/alertsSchema";
+import { dailyCapsStoreSchema } from "../shared/dailyCapsSchema";
+import { counterHistoryStoreSchema, countersStoreSchema } from "../shared/countersSchema";
 import { eventLogSchema } from "../shared/eventLogSchema";
+import { experimentsStoreSchema } from "../shared/experimentsSchema";
+import { parseExpTableLevels } from "../shared/expTables";
+import { expTablesStoreSchema } from "../shared/expTablesSchema";
+import { hotkeysStoreSchema } from "../shared/hotkeysSchema";
 import { memoryEntrySchema, memoryStoreSchema } from "../shared/memorySchema";
+import { pingHistoryStoreSchema } from "../shared/pingSchema";
 import { rulesStoreSchema } from "../shared/rulesSchema";
+import { parseSettings } from "../shared/settingsSchema";
+import { timerListOptionsSchema, timersStoreSchema } from "../shared/timersSchema";
+import { diffCounterValues, getCounterStatus } from "./counters";
+import { compactEventLog, downsampleCounterEvents } from "./retention";
 import { migrateLegacyPlan, validateWidgetSpec, WidgetSpec } from "../widgetSpec";
 
 const PROFILE_NAME = "default";
@@ -24,31 +65,26 @@ const PLAN_HISTORY_FILE = "plan.history.json";
 const EVENT_LOG_FILE = "event-log.json";
 const MEMORY_FILE = "memory.json";
 const RULES_FILE = "rules.json";
+const TIMERS_FILE = "timers.json";
+const COUNTERS_FILE = "counters.json";
+const COUNTER_HISTORY_FILE = "counter-history.json";
+const ALERTS_FILE = "alerts.json";
+const HOTKEYS_FILE = "hotkeys.json";
+const EXPERIMENTS_FILE = "experiments.json";
+const EXP_TABLES_FILE = "exp-tables.json";
+const DAILY_CAPS_FILE = "daily-caps.json";
+const PING_HISTORY_FILE = "ping-history.json";
 const CAPTURE_DIR = "captures";
+const EVENT_SCREENSHOT_DIR = "events";
 const CAPTURE_MAX_FILES = 10;
 const MEMORY_ENTRY_LIMIT = 500;
 const PLAN_SNAPSHOT_LIMIT = 50;
 const PLAN_HISTORY_LIMIT = PLAN_SNAPSHOT_LIMIT;
 const MEMORY_PAYLOAD_LIMIT_BYTES = 256 * 1024;
-
-const defaultSettings: OverlaySettings = {
-  bounds: null,
-  displayId: null,
-  opacity: 0.92,
-  clickThrough: false,
-  captureEnabled: false,
-  captureSourceType: null,
-  captureSourceId: null,
-  captureRoi: null,
-  uiMode: "gameplay",
-  llm: {
-    enabled: false,
-    provider: "ollama",
-    baseUrl: "http://127.0.0.1:11434/v1",
-    model: "llama3.2:1b",
-    apiKey: ""
-  }
-};
+const COUNTER_HISTORY_LIMIT = 5000;
+const PING_HISTORY_LIMIT = 20000;
+const NOTE_SEARCH_LIMIT = 50;
+const NOTE_SNIPPET_RADIUS = 60;
 
 const defaultEventLog: EventLog = {
   version: "1.0",
@@ -65,6 +101,58 @@ const defaultRules: RulesStore = {
   rules: []
 };
 
+const defaultTimers: TimersStore = {
+  version: "1.0",
+  timers: [],
+  listOptions: {
+    sort: "soonest",
+    groupByCategory: false
+  }
+};
+
+const defaultCounters: CountersStore = {
+  version: "1.0",
+  counters: []
+};
+
+const defaultCounterHistory: CounterHistoryStore = {
+  version: "1.0",
+  events: []
+};
+
+const defaultPingHistory: PingHistoryStore = {
+  version: "1.0",
+  samples: []
+};
+
+const defaultAlertRules: AlertRulesStore = {
+  version: "1.0",
+  rules: []
+};
+
+const defaultExperiments: ExperimentsStore = {
+  version: "1.0",
+  markers: []
+};
+
+const defaultExpTables: ExpTablesStore = {
+  version: "1.0",
+  activeTableId: null,
+  tables: []
+};
+
+const defaultHotkeys: HotkeysStore = {
+  version: "1.0",
+  bindings: [
+    {
+      id: "toggle-click-through",
+      accelerator: "Control+Shift+L",
+      actionId: "overlay.toggleClickThrough",
+      enabled: true
+    }
+  ]
+};
+
 const legacyMemoryEntrySchema = z.object({
   id: z.string(),
   createdAt: z.number().nonnegative(),
@@ -90,15 +178,6 @@ const ensureProfileDir = async (): Promise<string> => {
   return dir;
 };
 
-const readJson = async <T>(file: string, fallback: T): Promise<T> => {
-  try {
-    const raw = await fs.readFile(file, "utf-8");
-    return JSON.parse(raw) as T;
-  } catch {
-    return fallback;
-  }
-};
-
 const readJsonUnknown = async (
   file: string
 ): Promise<{ data: unknown | null; missing: boolean; error?: string }> => {
@@ -122,13 +201,63 @@ const readJsonUnknown = async (
   }
 };
 
+const pendingWrites = new Set<Promise<void>>();
+
 const writeJson = async <T>(file: string, data: T): Promise<void> => {
-  await fs.writeFile(file, JSON.stringify(data, null, 2), "utf-8");
+  const write = fs.writeFile(file, JSON.stringify(data, null, 2), "utf-8");
+  pendingWrites.add(write);
+  try {
+    await write;
+  } finally {
+    pendingWrites.delete(write);
+  }
+};
+
+export const flushPendingWrites = async (): Promise<void> => {
+  await Promise.allSettled([...pendingWrites]);
 };
 
 const sanitizeSegment = (value: string): string =>
   value.replace(/[^a-z0-9-_]+/gi, "_").replace(/^_+|_+$/g, "");
 
+const storeLocks = new Map<string, Promise<unknown>>();
+
+// Serializes read-modify-write cycles on one store so concurrent IPC calls
+// (rapid hotkey increments, for example) never interleave and lose updates.
+const withStoreLock = <T>(key: string, task: () => Promise<T>): Promise<T> => {
+  const previous = storeLocks.get(key) ?? Promise.resolve();
+  const run = previous.catch(() => undefined).then(task);
+  storeLocks.set(key, run);
+  run
+    .finally(() => {
+      if (storeLocks.get(key) === run) {
+        storeLocks.delete(key);
+      }
+    })
+    .catch(() => undefined);
+  return run;
+};
+
+const buildCounterId = () => {
+  const suffix = Math.random().toString(36).slice(2, 8);
+  return `counter-${Date.now()}-${suffix}`;
+};
+
+const buildExperimentId = () => {
+  const suffix = Math.random().toString(36).slice(2, 8);
+  return `experiment-${Date.now()}-${suffix}`;
+};
+
+const buildExpTableId = () => {
+  const suffix = Math.random().toString(36).slice(2, 8);
+  return `exp-table-${Date.now()}-${suffix}`;
+};
+
+const buildTimerId = () => {
+  const suffix = Math.random().toString(36).slice(2, 8);
+  return `timer-${Date.now()}-${suffix}`;
+};
+
 const buildSnapshotId = () => {
   const suffix = Math.random().toString(36).slice(2, 8);
   return `snap-${Date.now()}-${suffix}`;
@@ -156,12 +285,13 @@ const trimMemoryEntries = (entries: MemoryEntry[]): MemoryEntry[] => {
   const snapshots = sized
     .filter((entry) => entry.type === "plan_snapshot")
     .slice(0, PLAN_SNAPSHOT_LIMIT);
-  const snapshotIds = new Set(snapshots.map((entry) => entry.id));
-  const remainingSlots = Math.max(0, MEMORY_ENTRY_LIMIT - snapshots.length);
+  const pinned = sized.filter((entry) => entry.type === "note" && entry.payload.pinned);
+  const keptIds = new Set([...snapshots, ...pinned].map((entry) => entry.id));
+  const remainingSlots = Math.max(0, MEMORY_ENTRY_LIMIT - keptIds.size);
   const others = sized
-    .filter((entry) => entry.type !== "plan_snapshot" && !snapshotIds.has(entry.id))
+    .filter((entry) => entry.type !== "plan_snapshot" && !keptIds.has(entry.id))
     .slice(0, remainingSlots);
-  return [...snapshots, ...others];
+  return [...snapshots, ...pinned, ...others];
 };
 
 const sanitizeMemoryStore = (store: MemoryStore): MemoryStore => ({
@@ -217,9 +347,10 @@ const findSnapshotEntry = (entries: MemoryEntry[], snapshotId: string) =>
 
 export const loadSettings = async (): Promise<OverlaySettings> => {
   const dir = await ensureProfileDir();
-  const stored = await readJson(join(dir, SETTINGS_FILE), defaultSettings);
-  const merged = { ...defaultSettings, ...stored, captureEnabled: false };
-  if ((stored as Partial<OverlaySettings>).captureEnabled) {
+  const candidate = await readJsonUnknown(join(dir, SETTINGS_FILE));
+  const stored = parseSettings(candidate.data);
+  const merged = { ...stored, captureEnabled: false };
+  if (stored.captureEnabled) {
     try {
       await writeJson(join(dir, SETTINGS_FILE), merged);
     } catch {
@@ -231,7 +362,7 @@ export const loadSettings = async (): Promise<OverlaySettings> => {
 
 export const saveSettings = async (settings: OverlaySettings): Promise<void> => {
   const dir = await ensureProfileDir();
-  await writeJson(join(dir, SETTINGS_FILE), settings);
+  await writeJson(join(dir, SETTINGS_FILE), parseSettings(settings));
 };
 
 const buildFallbackWidgetSpec = (message: string): WidgetSpec => ({
@@ -371,42 +502,45 @@ export const savePlan = async (
 
   if (hasChanged) {
     try {
-      const memory = await loadMemory();
-      const history = await readPlanHistory(dir);
-      const baseSnapshotId = history.currentSnapshotId ?? undefined;
-      const snapshotId = buildSnapshotId();
-      const snapshotEntry: MemoryEntry = {
-        id: snapshotId,
-        profileId: PROFILE_NAME,
-        type: "plan_snapshot",
-        createdAt: Date.now(),
-        source: "system",
-        payload: {
-          snapshotId,
-          planJson: payload,
-          reason: meta?.reason?.trim() || "plan:update",
-          actor: meta?.actor ?? "user",
-          baseSnapshotId
-        }
-      };
-
-      if (getPayloadSize(snapshotEntry.payload) <= MEMORY_PAYLOAD_LIMIT_BYTES) {
-        const nextMemory = sanitizeMemoryStore({
-          ...memory,
-          entries: [snapshotEntry, ...memory.entries]
-        });
-        await writeJson(join(dir, MEMORY_FILE), nextMemory);
-        const nextHistory: PlanHistory = {
-          version: "1.0",
-          currentSnapshotId: snapshotId,
-          undo: baseSnapshotId
-            ? [...history.undo, baseSnapshotId].slice(-PLAN_HISTORY_LIMIT)
-            : history.undo,
-          redo: []
+      // Snapshots share memory.json with notes, so they take the same lock.
+      await withStoreLock(MEMORY_FILE, async () => {
+        const memory = await loadMemory();
+        const history = await readPlanHistory(dir);
+        const baseSnapshotId = history.currentSnapshotId ?? undefined;
+        const snapshotId = buildSnapshotId();
+        const snapshotEntry: MemoryEntry = {
+          id: snapshotId,
+          profileId: PROFILE_NAME,
+          type: "plan_snapshot",
+          createdAt: Date.now(),
+          source: "system",
+          payload: {
+            snapshotId,
+            planJson: payload,
+            reason: meta?.reason?.trim() || "plan:update",
+            actor: meta?.actor ?? "user",
+            baseSnapshotId
+          }
         };
-        const pruned = prunePlanHistory(nextHistory, nextMemory.entries, snapshotId);
-        await writeJson(historyPath, pruned);
-      }
+
+        if (getPayloadSize(snapshotEntry.payload) <= MEMORY_PAYLOAD_LIMIT_BYTES) {
+          const nextMemory = sanitizeMemoryStore({
+            ...memory,
+            entries: [snapshotEntry, ...memory.entries]
+          });
+          await writeJson(join(dir, MEMORY_FILE), nextMemory);
+          const nextHistory: PlanHistory = {
+            version: "1.0",
+            currentSnapshotId: snapshotId,
+            undo: baseSnapshotId
+              ? [...history.undo, baseSnapshotId].slice(-PLAN_HISTORY_LIMIT)
+              : history.undo,
+            redo: []
+          };
+          const pruned = prunePlanHistory(nextHistory, nextMemory.entries, snapshotId);
+          await writeJson(historyPath, pruned);
+        }
+      });
     } catch {
       // Ignore history failures; saving the plan should still work.
     }
@@ -624,7 +758,9 @@ export const loadMemory = async (): Promise<MemoryStore> => {
   return defaultMemory;
 };
 
-export const saveMemory = async (store: MemoryStore): Promise<void> => {
+// The write* memory helpers assume the caller holds the MEMORY_FILE lock so
+// notes, snapshots and renderer saves never overwrite one another.
+const writeMemory = async (store: MemoryStore): Promise<void> => {
   const sanitized = sanitizeMemoryStore(store);
   const validation = memoryStoreSchema.safeParse(sanitized);
   if (!validation.success) {
@@ -638,7 +774,10 @@ export const saveMemory = async (store: MemoryStore): Promise<void> => {
   await writeJson(join(dir, MEMORY_FILE), validation.data as MemoryStore);
 };
 
-export const addMemoryEntry = async (entry: MemoryEntry): Promise<MemoryStore> => {
+export const saveMemory = (store: MemoryStore): Promise<void> =>
+  withStoreLock(MEMORY_FILE, () => writeMemory(store));
+
+const insertMemoryEntry = async (entry: MemoryEntry): Promise<MemoryStore> => {
   const entryValidation = memoryEntrySchema.safeParse(entry);
   if (!entryValidation.success) {
     throw new Error(
@@ -660,7 +799,10 @@ export const addMemoryEntry = async (entry: MemoryEntry): Promise<MemoryStore> =
   return next;
 };
 
-export const deleteMemoryEntry = async (entryId: string): Promise<MemoryStore> => {
+export const addMemoryEntry = (entry: MemoryEntry): Promise<MemoryStore> =>
+  withStoreLock(MEMORY_FILE, () => insertMemoryEntry(entry));
+
+const removeMemoryEntry = async (entryId: string): Promise<MemoryStore> => {
   const dir = await ensureProfileDir();
   const current = await loadMemory();
   const next = sanitizeMemoryStore({
@@ -671,6 +813,148 @@ export const deleteMemoryEntry = async (entryId: string): Promise<MemoryStore> =
   return next;
 };
 
+export const deleteMemoryEntry = (entryId: string): Promise<MemoryStore> =>
+  withStoreLock(MEMORY_FILE, () => removeMemoryEntry(entryId));
+
+const buildNoteId = () => {
+  const suffix = Math.random().toString(36).slice(2, 8);
+  return `note-${Date.now()}-${suffix}`;
+};
+
+const isNoteEntry = (entry: MemoryEntry): entry is NoteEntry => entry.type === "note";
+
+const replaceNote = async (
+  noteId: string,
+  update: (note: NoteEntry) => NoteEntry
+): Promise<NoteEntry> =>
+  withStoreLock(MEMORY_FILE, async () => {
+    const current = await loadMemory();
+    const note = current.entries.filter(isNoteEntry).find((entry) => entry.id === noteId);
+    if (!note) {
+      throw new Error("Note not found.");
+    }
+    const next = update(note);
+    await writeMemory({
+      ...current,
+      entries: current.entries.map((entry) => (entry.id === noteId ? next : entry))
+    });
+    return next;
+  });
+
+export const createNote = async (text: string, tags?: string[]): Promise<NoteEntry> => {
+  const trimmed = text.trim();
+  if (!trimmed) {
+    throw new Error("Note text is empty.");
+  }
+  const note: NoteEntry = {
+    id: buildNoteId(),
+    profileId: PROFILE_NAME,
+    type: "note",
+    createdAt: Date.now(),
+    source: "user",
+    payload: { text: trimmed },
+    ...(tags && tags.length > 0 ? { tags } : {})
+  };
+  await addMemoryEntry(note);
+  return note;
+};
+
+// Pinned notes first, then most recently edited.
+export const listNotes = async (): Promise<NoteEntry[]> => {
+  const store = await loadMemory();
+  const touchedAt = (note: NoteEntry) => note.payload.updatedAt ?? note.createdAt;
+  return store.entries
+    .filter(isNoteEntry)
+    .sort(
+      (a, b) =>
+        Number(Boolean(b.payload.pinned)) - Number(Boolean(a.payload.pinned)) ||
+        touchedAt(b) - touchedAt(a)
+    );
+};
+
+export const updateNote = async (noteId: string, update: NoteUpdate): Promise<NoteEntry> => {
+  if (update.text !== undefined && !update.text.trim()) {
+    throw new Error("Note text is empty.");
+  }
+  return replaceNote(noteId, (note) => ({
+    ...note,
+    ...(update.tags !== undefined ? { tags: update.tags } : {}),
+    payload: {
+      ...note.payload,
+      ...(update.text !== undefined ? { text: update.text.trim() } : {}),
+      updatedAt: Date.now()
+    }
+  }));
+};
+
+export const pinNote = async (noteId: string, pinned: boolean): Promise<NoteEntry> =>
+  replaceNote(noteId, (note) => ({ ...note, payload: { ...note.payload, pinned } }));
+
+export const deleteNote = async (noteId: string): Promise<void> =>
+  withStoreLock(MEMORY_FILE, async () => {
+    const current = await loadMemory();
+    if (!current.entries.some((entry) => entry.id === noteId && isNoteEntry(entry))) {
+      throw new Error("Note not found.");
+    }
+    await removeMemoryEntry(noteId);
+  });
+
+const tokenizeQuery = (query: string): string[] =>
+  query
+    .toLowerCase()
+    .split(/\s+/)
+    .map((term) => term.trim())
+    .filter(Boolean);
+
+const countOccurrences = (haystack: string, needle: string): number => {
+  let count = 0;
+  let index = haystack.indexOf(needle);
+  while (index !== -1) {
+    count += 1;
+    index = haystack.indexOf(needle, index + needle.length);
+  }
+  return count;
+};
+
+const buildNoteSnippet = (text: string, term: string): string => {
+  const index = text.toLowerCase().indexOf(term);
+  if (index === -1) {
+    return text.slice(0, NOTE_SNIPPET_RADIUS * 2);
+  }
+  const start = Math.max(0, index - NOTE_SNIPPET_RADIUS);
+  const end = Math.min(text.length, index + term.length + NOTE_SNIPPET_RADIUS);
+  return `${start > 0 ? "..." : ""}${text.slice(start, end)}${end < text.length ? "..." : ""}`;
+};
+
+// Every query term must appear in the note text or its tags; hits are ranked
+// by total term occurrences, newest first on ties.
+export const searchNotes = async (query: string): Promise<NoteSearchHit[]> => {
+  const terms = tokenizeQuery(query);
+  if (terms.length === 0) {
+    return [];
+  }
+  const memory = await loadMemory();
+  const hits: NoteSearchHit[] = [];
+  memory.entries.forEach((entry) => {
+    if (entry.type !== "note") {
+      return;
+    }
+    const haystack = `${entry.payload.text} ${(entry.tags ?? []).join(" ")}`.toLowerCase();
+    const counts = terms.map((term) => countOccurrences(haystack, term));
+    if (counts.some((count) => count === 0)) {
+      return;
+    }
+    hits.push({
+      entry,
+      score: counts.reduce((sum, count) => sum + count, 0),
+      snippet: buildNoteSnippet(entry.payload.text, terms[0])
+    });
+  });
+  return hits
+    .sort((a, b) => b.score - a.score || b.entry.createdAt - a.entry.createdAt)
+    .slice(0, NOTE_SEARCH_LIMIT);
+};
+
 export const loadRules = async (): Promise<RulesStore> => {
   const dir = await ensureProfileDir();
   const path = join(dir, RULES_FILE);
@@ -697,6 +981,566 @@ export const saveRules = async (store: RulesStore): Promise<void> => {
   await writeJson(join(dir, RULES_FILE), validation.data as RulesStore);
 };
 
+export const loadTimers = async (): Promise<TimersStore> => {
+  const dir = await ensureProfileDir();
+  const path = join(dir, TIMERS_FILE);
+  const candidate = await readJsonUnknown(path);
+  if (candidate.data !== null) {
+    const validation = timersStoreSchema.safeParse(candidate.data);
+    if (validation.success) {
+      return validation.data as TimersStore;
+    }
+  }
+  return defaultTimers;
+};
+
+export const loadAlertRules = async (): Promise<AlertRulesStore> => {
+  const dir = await ensureProfileDir();
+  const path = join(dir, ALERTS_FILE);
+  const candidate = await readJsonUnknown(path);
+  if (candidate.data !== null) {
+    const validation = alertRulesStoreSchema.safeParse(candidate.data);
+    if (validation.success) {
+      return validation.data as AlertRulesStore;
+    }
+  }
+  return defaultAlertRules;
+};
+
+export const saveAlertRules = async (store: AlertRulesStore): Promise<void> => {
+  const validation = alertRulesStoreSchema.safeParse(store);
+  if (!validation.success) {
+    throw new Error(
+      `Refusing to save invalid alert rules: ${validation.error.errors
+        .map((err) => err.message)
+        .join("; ")}`
+    );
+  }
+  const dir = await ensureProfileDir();
+  await writeJson(join(dir, ALERTS_FILE), validation.data as AlertRulesStore);
+};
+
+export const loadDailyCaps = async (): Promise<DailyCapsStore> => {
+  const dir = await ensureProfileDir();
+  const candidate = await readJsonUnknown(join(dir, DAILY_CAPS_FILE));
+  if (candidate.data !== null) {
+    const validation = dailyCapsStoreSchema.safeParse(candidate.data);
+    if (validation.success) {
+      return validation.data as DailyCapsStore;
+    }
+  }
+  return { version: "1.0", caps: [] };
+};
+
+export const saveDailyCaps = async (store: DailyCapsStore): Promise<void> => {
+  const validation = dailyCapsStoreSchema.safeParse(store);
+  if (!validation.success) {
+    throw new Error(
+      `Refusing to save invalid daily caps: ${validation.error.errors
+        .map((err) => err.message)
+        .join("; ")}`
+    );
+  }
+  const dir = await ensureProfileDir();
+  await writeJson(join(dir, DAILY_CAPS_FILE), validation.data as DailyCapsStore);
+};
+
+export const loadHotkeys = async (): Promise<HotkeysStore> => {
+  const dir = await ensureProfileDir();
+  const candidate = await readJsonUnknown(join(dir, HOTKEYS_FILE));
+  if (candidate.data !== null) {
+    const validation = hotkeysStoreSchema.safeParse(candidate.data);
+    if (validation.success) {
+      return validation.data as HotkeysStore;
+    }
+  }
+  return defaultHotkeys;
+};
+
+export const saveHotkeys = async (store: HotkeysStore): Promise<void> => {
+  const validation = hotkeysStoreSchema.safeParse(store);
+  if (!validation.success) {
+    throw new Error(
+      `Refusing to save invalid hotkeys: ${validation.error.errors
+        .map((err) => err.message)
+        .join("; ")}`
+    );
+  }
+  const dir = await ensureProfileDir();
+  await writeJson(join(dir, HOTKEYS_FILE), validation.data as HotkeysStore);
+};
+
+export const upsertHotkey = async (binding: HotkeyBinding): Promise<HotkeysStore> =>
+  withStoreLock(HOTKEYS_FILE, async () => {
+    const store = await loadHotkeys();
+    const exists = store.bindings.some((candidate) => candidate.id === binding.id);
+    const next: HotkeysStore = {
+      ...store,
+      bindings: exists
+        ? store.bindings.map((candidate) => (candidate.id === binding.id ? binding : candidate))
+        : [...store.bindings, binding]
+    };
+    await saveHotkeys(next);
+    return next;
+  });
+
+export const deleteHotkey = async (bindingId: string): Promise<HotkeysStore> =>
+  withStoreLock(HOTKEYS_FILE, async () => {
+    const store = await loadHotkeys();
+    const next = {
+      ...store,
+      bindings: store.bindings.filter((binding) => binding.id !== bindingId)
+    };
+    await saveHotkeys(next);
+    return next;
+  });
+
+export const loadExperiments = async (): Promise<ExperimentsStore> => {
+  const dir = await ensureProfileDir();
+  const candidate = await readJsonUnknown(join(dir, EXPERIMENTS_FILE));
+  if (candidate.data !== null) {
+    const validation = experimentsStoreSchema.safeParse(candidate.data);
+    if (validation.success) {
+      return validation.data as ExperimentsStore;
+    }
+  }
+  return defaultExperiments;
+};
+
+const saveExperiments = async (store: ExperimentsStore): Promise<void> => {
+  const validation = experimentsStoreSchema.safeParse(store);
+  if (!validation.success) {
+    throw new Error(
+      `Refusing to save invalid experiments: ${validation.error.errors
+        .map((err) => err.message)
+        .join("; ")}`
+    );
+  }
+  const dir = await ensureProfileDir();
+  await writeJson(join(dir, EXPERIMENTS_FILE), validation.data as ExperimentsStore);
+};
+
+export const markExperiment = async (label: string, note?: string): Promise<ExperimentMarker> => {
+  const trimmed = label.trim();
+  if (!trimmed) {
+    throw new Error("Experiment label is required.");
+  }
+  const marker: ExperimentMarker = {
+    id: buildExperimentId(),
+    label: trimmed,
+    markedAt: Date.now(),
+    ...(note?.trim() ? { note: note.trim() } : {})
+  };
+  return withStoreLock(EXPERIMENTS_FILE, async () => {
+    const store = await loadExperiments();
+    await saveExperiments({ ...store, markers: [...store.markers, marker] });
+    return marker;
+  });
+};
+
+export const deleteExperiment = async (label: string): Promise<void> =>
+  withStoreLock(EXPERIMENTS_FILE, async () => {
+    const store = await loadExperiments();
+    await saveExperiments({
+      ...store,
+      markers: store.markers.filter(
+        (marker) => marker.label.toLowerCase() !== label.trim().toLowerCase()
+      )
+    });
+  });
+
+export const loadExpTables = async (): Promise<ExpTablesStore> => {
+  const dir = await ensureProfileDir();
+  const candidate = await readJsonUnknown(join(dir, EXP_TABLES_FILE));
+  if (candidate.data !== null) {
+    const validation = expTablesStoreSchema.safeParse(candidate.data);
+    if (validation.success) {
+      return validation.data as ExpTablesStore;
+    }
+  }
+  return defaultExpTables;
+};
+
+const saveExpTables = async (store: ExpTablesStore): Promise<void> => {
+  const validation = expTablesStoreSchema.safeParse(store);
+  if (!validation.success) {
+    throw new Error(
+      `Refusing to save invalid exp tables: ${validation.error.errors
+        .map((err) => err.message)
+        .join("; ")}`
+    );
+  }
+  const dir = await ensureProfileDir();
+  await writeJson(join(dir, EXP_TABLES_FILE), validation.data as ExpTablesStore);
+};
+
+// Re-importing a name replaces that table in place; the first table
+// imported becomes active.
+export const importExpTable = async (name: string, content: string): Promise<ExpTable> => {
+  const trimmed = name.trim();
+  if (!trimmed) {
+    throw new Error("Exp table name is required.");
+  }
+  const levels = parseExpTableLevels(content);
+  return withStoreLock(EXP_TABLES_FILE, async () => {
+    const store = await loadExpTables();
+    const existing = store.tables.find(
+      (table) => table.name.toLowerCase() === trimmed.toLowerCase()
+    );
+    const table: ExpTable = {
+      id: existing?.id ?? buildExpTableId(),
+      name: trimmed,
+      importedAt: Date.now(),
+      levels
+    };
+    await saveExpTables({
+      ...store,
+      activeTableId: store.activeTableId ?? table.id,
+      tables: existing
+        ? store.tables.map((candidate) => (candidate.id === table.id ? table : candidate))
+        : [...store.tables, table]
+    });
+    return table;
+  });
+};
+
+export const setActiveExpTable = async (tableId: string | null): Promise<ExpTablesStore> =>
+  withStoreLock(EXP_TABLES_FILE, async () => {
+    const store = await loadExpTables();
+    if (tableId !== null && !store.tables.some((table) => table.id === tableId)) {
+      throw new Error(`Exp table "${tableId}" not found.`);
+    }
+    const next = { ...store, activeTableId: tableId };
+    await saveExpTables(next);
+    return next;
+  });
+
+// Callers must hold the TIMERS_FILE lock.
+const writeTimers = async (store: TimersStore): Promise<void> => {
+  const validation = timersStoreSchema.safeParse(store);
+  if (!validation.success) {
+    throw new Error(
+      `Refusing to save invalid timers store: ${validation.error.errors
+        .map((err) => err.message)
+        .join("; ")}`
+    );
+  }
+  const dir = await ensureProfileDir();
+  await writeJson(join(dir, TIMERS_FILE), validation.data as TimersStore);
+};
+
+export const saveTimers = async (store: TimersStore): Promise<void> =>
+  withStoreLock(TIMERS_FILE, () => writeTimers(store));
+
+const replaceTimer = async (
+  timerId: string,
+  update: (timer: TimerRecord) => TimerRecord
+): Promise<TimerRecord> =>
+  withStoreLock(TIMERS_FILE, async () => {
+    const store = await loadTimers();
+    const current = store.timers.find((timer) => timer.id === timerId);
+    if (!current) {
+      throw new Error("Timer not found.");
+    }
+    const next = update(current);
+    await writeTimers({
+      ...store,
+      timers: store.timers.map((timer) => (timer.id === timerId ? next : timer))
+    });
+    return next;
+  });
+
+export const createTimer = async (input: TimerCreateInput): Promise<TimerRecord> => {
+  if (!Number.isFinite(input.durationSeconds) || input.durationSeconds <= 0) {
+    throw new Error("Timer duration must be a positive number of seconds.");
+  }
+  const now = Date.now();
+  const timer: TimerRecord = {
+    id: buildTimerId(),
+    label: input.label.trim(),
+    endsAt: now + Math.round(input.durationSeconds * 1000),
+    createdAt: now,
+    ...(input.category ? { category: input.category } : {}),
+    ...(input.color ? { color: input.color } : {}),
+    ...(input.repeatSeconds ? { repeatSeconds: input.repeatSeconds } : {})
+  };
+  return withStoreLock(TIMERS_FILE, async () => {
+    const store = await loadTimers();
+    await writeTimers({ ...store, timers: [...store.timers, timer] });
+    return timer;
+  });
+};
+
+export const updateTimer = async (timerId: string, update: TimerUpdate): Promise<TimerRecord> =>
+  replaceTimer(timerId, (timer) => ({
+    ...timer,
+    ...update,
+    id: timer.id,
+    createdAt: timer.createdAt
+  }));
+
+export const deleteTimer = async (timerId: string): Promise<void> =>
+  withStoreLock(TIMERS_FILE, async () => {
+    const store = await loadTimers();
+    const timers = store.timers.filter((timer) => timer.id !== timerId);
+    if (timers.length === store.timers.length) {
+      throw new Error("Timer not found.");
+    }
+    await writeTimers({ ...store, timers });
+  });
+
+// Extending an already expired timer restarts it from now.
+export const extendTimer = async (timerId: string, seconds: number): Promise<TimerRecord> => {
+  if (!Number.isFinite(seconds) || seconds === 0) {
+    throw new Error("Extension must be a non-zero number of seconds.");
+  }
+  return replaceTimer(timerId, (timer) => ({
+    ...timer,
+    endsAt: Math.max(Date.now(), timer.endsAt) + Math.round(seconds * 1000)
+  }));
+};
+
+const compareTimers = (sort: TimerListOptions["sort"]) => (a: TimerRecord, b: TimerRecord) => {
+  if (sort === "label") {
+    return a.label.localeCompare(b.label) || a.endsAt - b.endsAt;
+  }
+  if (sort === "created") {
+    return a.createdAt - b.createdAt;
+  }
+  return a.endsAt - b.endsAt || a.label.localeCompare(b.label);
+};
+
+const groupTimersByCategory = (timers: TimerRecord[]): TimerGroup[] => {
+  const groups = new Map<string | null, TimerRecord[]>();
+  timers.forEach((timer) => {
+    const category = timer.category?.trim() || null;
+    const bucket = groups.get(category) ?? [];
+    bucket.push(timer);
+    groups.set(category, bucket);
+  });
+  return [...groups.entries()]
+    .sort(([a], [b]) => {
+      if (a === null) {
+        return b === null ? 0 : 1;
+      }
+      if (b === null) {
+        return -1;
+      }
+      return a.localeCompare(b);
+    })
+    .map(([category, grouped]) => ({ category, timers: grouped }));
+};
+
+export const listTimers = async (
+  overrides?: Partial<TimerListOptions>
+): Promise<TimerListResult> => {
+  const store = await loadTimers();
+  const options = { ...store.listOptions, ...overrides };
+  const timers = [...store.timers].sort(compareTimers(options.sort));
+  return {
+    options,
+    timers,
+    groups: options.groupByCategory ? groupTimersByCategory(timers) : []
+  };
+};
+
+export const saveTimerListOptions = async (
+  options: TimerListOptions
+): Promise<TimerListOptions> => {
+  const validation = timerListOptionsSchema.safeParse(options);
+  if (!validation.success) {
+    throw new Error(
+      `Refusing to save invalid timer list options: ${validation.error.errors
+        .map((err) => err.message)
+        .join("; ")}`
+    );
+  }
+  return withStoreLock(TIMERS_FILE, async () => {
+    const current = await loadTimers();
+    await writeTimers({ ...current, listOptions: validation.data });
+    return validation.data;
+  });
+};
+
+export const loadCounters = async (): Promise<CountersStore> => {
+  const dir = await ensureProfileDir();
+  const path = join(dir, COUNTERS_FILE);
+  const candidate = await readJsonUnknown(path);
+  if (candidate.data !== null) {
+    const validation = countersStoreSchema.safeParse(candidate.data);
+    if (validation.success) {
+      return validation.data as CountersStore;
+    }
+  }
+  return defaultCounters;
+};
+
+const loadCounterHistoryStore = async (dir: string): Promise<CounterHistoryStore> => {
+  const candidate = await readJsonUnknown(join(dir, COUNTER_HISTORY_FILE));
+  if (candidate.data !== null) {
+    const validation = counterHistoryStoreSchema.safeParse(candidate.data);
+    if (validation.success) {
+      return validation.data as CounterHistoryStore;
+    }
+  }
+  return defaultCounterHistory;
+};
+
+// Callers must hold the COUNTERS_FILE lock; the store and its history are one
+// read-modify-write unit.
+const writeCounters = async (store: CountersStore): Promise<CountersStore> => {
+  const validation = countersStoreSchema.safeParse(store);
+  if (!validation.success) {
+    throw new Error(
+      `Refusing to save invalid counters store: ${validation.error.errors
+        .map((err) => err.message)
+        .join("; ")}`
+    );
+  }
+  const dir = await ensureProfileDir();
+  const previous = await loadCounters();
+  const next = validation.data as CountersStore;
+  await writeJson(join(dir, COUNTERS_FILE), next);
+
+  const changes = diffCounterValues(previous.counters, next.counters, Date.now());
+  if (changes.length > 0) {
+    try {
+      const history = await loadCounterHistoryStore(dir);
+      await writeJson(join(dir, COUNTER_HISTORY_FILE), {
+        version: "1.0",
+        events: [...history.events, ...changes].slice(-COUNTER_HISTORY_LIMIT)
+      });
+    } catch {
+      // History is best-effort; the counter value itself is already saved.
+    }
+  }
+  return previous;
+};
+
+// Resolves to the store as it was before the save, for goal detection.
+export const saveCounters = (store: CountersStore): Promise<CountersStore> =>
+  withStoreLock(COUNTERS_FILE, () => writeCounters(store));
+
+export const applyRetention = async (
+  settings: RetentionSettings,
+  now = Date.now()
+): Promise<RetentionReport> => {
+  const dir = await ensureProfileDir();
+  const counterEvents = await withStoreLock(COUNTERS_FILE, async () => {
+    const history = await loadCounterHistoryStore(dir);
+    const events = downsampleCounterEvents(history.events, settings, now);
+    if (events.length !== history.events.length) {
+      await writeJson(join(dir, COUNTER_HISTORY_FILE), { ...history, events });
+    }
+    return { before: history.events.length, after: events.length };
+  });
+  const { log, compacted } = compactEventLog(await loadEventLog(), settings, now);
+  if (compacted > 0) {
+    await saveEventLog(log);
+  }
+  return {
+    ranAt: now,
+    eventLogEntriesCompacted: compacted,
+    counterEventsBefore: counterEvents.before,
+    counterEventsAfter: counterEvents.after
+  };
+};
+
+export const getCounterHistory = async (
+  counterId: string,
+  from?: number,
+  to?: number
+): Promise<CounterEvent[]> => {
+  const dir = await ensureProfileDir();
+  const history = await loadCounterHistoryStore(dir);
+  return history.events.filter(
+    (event) =>
+      event.counterId === counterId &&
+      (from === undefined || event.timestamp >= from) &&
+      (to === undefined || event.timestamp <= to)
+  );
+};
+
+const loadPingHistoryStore = async (dir: string): Promise<PingHistoryStore> => {
+  const candidate = await readJsonUnknown(join(dir, PING_HISTORY_FILE));
+  if (candidate.data !== null) {
+    const validation = pingHistoryStoreSchema.safeParse(candidate.data);
+    if (validation.success) {
+      return validation.data;
+    }
+  }
+  return defaultPingHistory;
+};
+
+export const appendPingSamples = async (samples: PingHistorySample[]): Promise<void> =>
+  withStoreLock(PING_HISTORY_FILE, async () => {
+    const dir = await ensureProfileDir();
+    const history = await loadPingHistoryStore(dir);
+    await writeJson(join(dir, PING_HISTORY_FILE), {
+      version: "1.0",
+      samples: [...history.samples, ...samples].slice(-PING_HISTORY_LIMIT)
+    });
+  });
+
+export const getPingHistory = async (
+  from?: number,
+  to?: number
+): Promise<PingHistorySample[]> => {
+  const dir = await ensureProfileDir();
+  const history = await loadPingHistoryStore(dir);
+  return history.samples.filter(
+    (sample) => (from === undefined || sample.at >= from) && (to === undefined || sample.at <= to)
+  );
+};
+
+export const updateCounter = async (
+  counterId: string,
+  update: (counter: CounterRecord) => CounterRecord
+): Promise<{ before: CounterRecord; after: CounterRecord }> =>
+  withStoreLock(COUNTERS_FILE, async () => {
+    const store = await loadCounters();
+    const before = store.counters.find((counter) => counter.id === counterId);
+    if (!before) {
+      throw new Error("Counter not found.");
+    }
+    const after = update(before);
+    await writeCounters({
+      ...store,
+      counters: store.counters.map((counter) => (counter.id === counterId ? after : counter))
+    });
+    return { before, after };
+  });
+
+export const createCounter = async (input: CounterCreateInput): Promise<CounterRecord> => {
+  const counter: CounterRecord = {
+    ...input,
+    id: buildCounterId(),
+    label: input.label.trim(),
+    value: input.value ?? 0,
+    createdAt: Date.now()
+  };
+  return withStoreLock(COUNTERS_FILE, async () => {
+    const store = await loadCounters();
+    await writeCounters({ ...store, counters: [...store.counters, counter] });
+    return counter;
+  });
+};
+
+export const deleteCounter = async (counterId: string): Promise<void> =>
+  withStoreLock(COUNTERS_FILE, async () => {
+    const store = await loadCounters();
+    const counters = store.counters.filter((counter) => counter.id !== counterId);
+    if (counters.length === store.counters.length) {
+      throw new Error("Counter not found.");
+    }
+    await writeCounters({ ...store, counters });
+  });
+
+export const listCounters = async (): Promise<CounterStatus[]> => {
+  const store = await loadCounters();
+  return store.counters.map(getCounterStatus);
+};
+
 export const saveCapture = async (
   image: Buffer,
   sourceId: string,
@@ -726,3 +1570,145 @@ export const saveCapture = async (
   }
   return filePath;
 };
+
+// Event screenshots are kept as proof of drops and milestones, so unlike OCR
+// captures they are never rotated out.
+export const saveEventScreenshot = async (
+  image: Buffer,
+  label: string,
+  capturedAt: number
+): Promise<string> => {
+  const dir = await ensureProfileDir();
+  const eventsDir = join(dir, EVENT_SCREENSHOT_DIR);
+  await fs.mkdir(eventsDir, { recursive: true });
+  const timestamp = new Date(capturedAt).toISOString().replace(/[:.]/g, "-");
+  const filePath = join(eventsDir, `${timestamp}-${sanitizeSegment(label) || "event"}.png`);
+  await fs.writeFile(filePath, image);
+  return filePath;
+};
+
+const BACKUP_FILES = [
+  SETTINGS_FILE,
+  PLAN_FILE,
+  PLAN_LAST_GOOD_FILE,
+  PLAN_HISTORY_FILE,
+  EVENT_LOG_FILE,
+  MEMORY_FILE,
+  RULES_FILE,
+  TIMERS_FILE,
+  COUNTERS_FILE,
+  COUNTER_HISTORY_FILE,
+  ALERTS_FILE,
+  HOTKEYS_FILE,
+  EXPERIMENTS_FILE,
+  EXP_TABLES_FILE,
+  DAILY_CAPS_FILE,
+  PING_HISTORY_FILE
+];
+
+const schemaCheck =
+  (...schemas: z.ZodTypeAny[]) =>
+  (data: unknown): string | null => {
+    const results = schemas.map((schema) => schema.safeParse(data));
+    if (results.some((result) => result.success)) {
+      return null;
+    }
+    const first = results[0];
+    return first.success ? null : first.error.errors.map((err) => err.message).join("; ");
+  };
+
+const planCheck = (data: unknown) => {
+  const result = validateWidgetSpec(data);
+  return result.ok ? null : result.error;
+};
+
+// Files without a strict schema (settings, plan history) only need to be JSON
+// objects; their loaders already fall back to defaults for anything they reject.
+const backupValidators: Record<string, (data: unknown) => string | null> = {
+  [PLAN_FILE]: planCheck,
+  [PLAN_LAST_GOOD_FILE]: planCheck,
+  [EVENT_LOG_FILE]: schemaCheck(eventLogSchema),
+  [MEMORY_FILE]: schemaCheck(memoryStoreSchema, legacyMemoryStoreSchema),
+  [RULES_FILE]: schemaCheck(rulesStoreSchema),
+  [TIMERS_FILE]: schemaCheck(timersStoreSchema),
+  [COUNTERS_FILE]: schemaCheck(countersStoreSchema),
+  [COUNTER_HISTORY_FILE]: schemaCheck(counterHistoryStoreSchema),
+  [ALERTS_FILE]: schemaCheck(alertRulesStoreSchema),
+  [HOTKEYS_FILE]: schemaCheck(hotkeysStoreSchema),
+  [EXPERIMENTS_FILE]: schemaCheck(experimentsStoreSchema),
+  [EXP_TABLES_FILE]: schemaCheck(expTablesStoreSchema),
+  [DAILY_CAPS_FILE]: schemaCheck(dailyCapsStoreSchema),
+  [PING_HISTORY_FILE]: schemaCheck(pingHistoryStoreSchema)
+};
+
+const profileBackupSchema = z.object({
+  version: z.literal("1.0"),
+  profileId: z.string(),
+  createdAt: z.number().nonnegative(),
+  files: z.record(z.unknown())
+});
+
+export const backupProfile = async (destPath: string): Promise<ProfileBackupResult> => {
+  if (!isAbsolute(destPath)) {
+    throw new Error("Backup path must be absolute.");
+  }
+  await flushPendingWrites();
+  const dir = await ensureProfileDir();
+  const files: Record<string, unknown> = {};
+  for (const name of BACKUP_FILES) {
+    const candidate = await readJsonUnknown(join(dir, name));
+    if (candidate.data !== null) {
+      files[name] = candidate.data;
+    }
+  }
+  const createdAt = Date.now();
+  await fs.writeFile(
+    destPath,
+    JSON.stringify({ version: "1.0", profileId: PROFILE_NAME, createdAt, files }, null, 2),
+    "utf-8"
+  );
+  return { path: destPath, createdAt, files: Object.keys(files) };
+};
+
+// Every file is validated before any is written, so a bad backup leaves the
+// current profile untouched. Profile files the backup does not contain are
+// removed so their loaders fall back to defaults instead of mixing the old
+// profile into the restored one. Capture screenshots are not part of backups.
+export const restoreProfile = async (srcPath: string): Promise<ProfileBackupResult> => {
+  const candidate = await readJsonUnknown(srcPath);
+  if (candidate.data === null) {
+    throw new Error(`Could not read backup: ${candidate.error ?? "file missing"}`);
+  }
+  const backup = profileBackupSchema.safeParse(candidate.data);
+  if (!backup.success) {
+    throw new Error("Not an Overlay MMO profile backup.");
+  }
+  const problems = Object.entries(backup.data.files).flatMap(([name, data]) => {
+    if (!BACKUP_FILES.includes(name)) {
+      return [`${name}: unexpected file`];
+    }
+    if (typeof data !== "object" || data === null) {
+      return [`${name}: not a JSON object`];
+    }
+    const error = backupValidators[name]?.(data) ?? null;
+    return error ? [`${name}: ${error}`] : [];
+  });
+  if (problems.length > 0) {
+    throw new Error(`Refusing to restore invalid backup: ${problems.join("; ")}`);
+  }
+  await flushPendingWrites();
+  const dir = await ensureProfileDir();
+  for (const name of BACKUP_FILES) {
+    const path = join(dir, name);
+    await withStoreLock(name, () =>
+      name in backup.data.files
+        ? writeJson(path, backup.data.files[name])
+        : fs.rm(path, { force: true })
+    );
+  }
+  return {
+    path: srcPath,
+    createdAt: backup.data.createdAt,
+    files: Object.keys(backup.data.files)
+  };
+};
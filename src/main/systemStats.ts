@@ -0,0 +1,184 @@
+import { app } from "electron";
+import { execFile } from "child_process";
+import { cpus, freemem, totalmem } from "os";
+import { GameProcessStats, OverlaySettings, SystemStats } from "../shared/ipc";
+import { logError } from "./logging";
+
+const SYSTEM_STATS_INTERVAL_MS = 5000;
+
+// Resolves the process that owns the captured window ($Id is the window
+// handle) and reports its cumulative CPU time and working set.
+const GAME_PROCESS_SCRIPT = `
+Add-Type @"
+using System;
+using System.Runtime.InteropServices;
+public class Win32Owner {
+  [DllImport("user32.dll")] public static extern uint GetWindowThreadProcessId(IntPtr hWnd, out uint lpdwProcessId);
+}
+"@
+$procId = 0
+[Win32Owner]::GetWindowThreadProcessId([IntPtr]::new($Id), [ref]$procId) | Out-Null
+$proc = Get-Process -Id $procId -ErrorAction Stop
+[pscustomobject]@{
+  pid = $proc.Id
+  name = $proc.ProcessName
+  cpuSeconds = $proc.TotalProcessorTime.TotalSeconds
+  memoryBytes = $proc.WorkingSet64
+} | ConvertTo-Json -Compress
+`.trim();
+
+type CpuTimes = { idle: number; total: number };
+
+type GameProcessReading = {
+  pid: number;
+  name: string;
+  cpuSeconds: number;
+  memoryBytes: number;
+  at: number;
+};
+
+// Only the periodic sampler moves these baselines; on-demand reads return the
+// last published sample so they cannot shorten the sampler's CPU window.
+let previousCpu: CpuTimes | null = null;
+let previousGame: GameProcessReading | null = null;
+let lastStats: SystemStats | null = null;
+let statsTimer: NodeJS.Timeout | null = null;
+let tickInFlight = false;
+
+const readCpuTimes = (): CpuTimes =>
+  cpus().reduce(
+    (acc, cpu) => {
+      const { user, nice, sys, idle, irq } = cpu.times;
+      return { idle: acc.idle + idle, total: acc.total + user + nice + sys + idle + irq };
+    },
+    { idle: 0, total: 0 }
+  );
+
+// CPU usage is a delta between two readings, so the first sample after
+// startup reports null rather than a since-boot average.
+const sampleCpuPercent = (): number | null => {
+  const current = readCpuTimes();
+  const previous = previousCpu;
+  previousCpu = current;
+  if (!previous || current.total <= previous.total) {
+    return null;
+  }
+  const idle = current.idle - previous.idle;
+  const total = current.total - previous.total;
+  return ((total - idle) / total) * 100;
+};
+
+const readGameProcess = (windowId: string): Promise<GameProcessReading> =>
+  new Promise((resolve, reject) => {
+    const script = `$Id = [Int64]${windowId}\n${GAME_PROCESS_SCRIPT}`;
+    execFile(
+      "powershell.exe",
+      [
+        "-NoProfile",
+        "-ExecutionPolicy",
+        "Bypass",
+        "-EncodedCommand",
+        Buffer.from(script, "utf16le").toString("base64")
+      ],
+      { windowsHide: true },
+      (error, stdout, stderr) => {
+        if (error) {
+          reject(new Error(stderr?.trim() || error.message));
+          return;
+        }
+        try {
+          const parsed = JSON.parse(stdout.trim()) as Omit<GameProcessReading, "at">;
+          resolve({ ...parsed, at: Date.now() });
+        } catch (parseError: unknown) {
+          reject(parseError);
+        }
+      }
+    );
+  });
+
+// The game is the process owning the captured window, so it is only known
+// on Windows while capturing a window (not a whole display). Its CPU share is
+// normalized across all cores like the system figure.
+const sampleGameProcess = async (settings: OverlaySettings): Promise<GameProcessStats | null> => {
+  const windowId = settings.captureSourceType === "window" ? settings.captureSourceId : null;
+  if (process.platform !== "win32" || !windowId || !/^\d+$/.test(windowId)) {
+    previousGame = null;
+    return null;
+  }
+  let current: GameProcessReading;
+  try {
+    current = await readGameProcess(windowId);
+  } catch (error: unknown) {
+    previousGame = null;
+    await logError("system.stats.game.failed", {
+      error: error instanceof Error ? error.message : String(error)
+    });
+    return null;
+  }
+  const previous = previousGame;
+  previousGame = current;
+  const elapsedSeconds = previous ? (current.at - previous.at) / 1000 : 0;
+  const cpuPercent =
+    previous && previous.pid === current.pid && elapsedSeconds > 0
+      ? ((current.cpuSeconds - previous.cpuSeconds) / elapsedSeconds / cpus().length) * 100
+      : null;
+  return { processName: current.name, cpuPercent, memoryBytes: current.memoryBytes };
+};
+
+const buildStats = (cpuPercent: number | null, game: GameProcessStats | null): SystemStats => {
+  const metrics = app.getAppMetrics();
+  const total = totalmem();
+  return {
+    sampledAt: Date.now(),
+    cpuPercent,
+    memoryUsedBytes: total - freemem(),
+    memoryTotalBytes: total,
+    appCpuPercent: metrics.reduce((sum, metric) => sum + metric.cpu.percentCPUUsage, 0),
+    appMemoryBytes: metrics.reduce((sum, metric) => sum + metric.memory.workingSetSize * 1024, 0),
+    game
+  };
+};
+
+// Before the first periodic sample there is no CPU window yet, so the
+// percentages are null.
+export const getSystemStats = (): SystemStats => lastStats ?? buildStats(null, null);
+
+export const startSystemStats = (
+  publish: (stats: SystemStats) => void,
+  getSettings: () => Promise<OverlaySettings>
+) => {
+  if (statsTimer) {
+    return;
+  }
+  sampleCpuPercent();
+  statsTimer = setInterval(() => {
+    if (tickInFlight) {
+      return;
+    }
+    tickInFlight = true;
+    getSettings()
+      .then(sampleGameProcess)
+      .then((game) => {
+        lastStats = buildStats(sampleCpuPercent(), game);
+        publish(lastStats);
+      })
+      .catch((error: unknown) =>
+        logError("system.stats.tick.failed", {
+          error: error instanceof Error ? error.message : String(error)
+        })
+      )
+      .finally(() => {
+        tickInFlight = false;
+      });
+  }, SYSTEM_STATS_INTERVAL_MS);
+};
+
+export const stopSystemStats = () => {
+  if (statsTimer) {
+    clearInterval(statsTimer);
+    statsTimer = null;
+  }
+  previousCpu = null;
+  previousGame = null;
+  lastStats = null;
+};
@@ -0,0 +1,99 @@
+import { TimerExpiredEvent, TimerRecord, UiLanguage } from "../shared/ipc";
+import { translate } from "./i18n";
+import { logError } from "./logging";
+import { loadTimers, updateTimer } from "./storage";
+
+const TIMER_TICK_MS = 1000;
+
+let tickTimer: NodeJS.Timeout | null = null;
+let tickInFlight = false;
+// Keyed by id and deadline so extending or editing a timer re-arms it.
+const firedDeadlines = new Set<string>();
+
+const deadlineKey = (timer: TimerRecord) => `${timer.id}:${timer.endsAt}`;
+
+const nextRepeatDeadline = (timer: TimerRecord, now: number) => {
+  if (!timer.repeatSeconds) {
+    return null;
+  }
+  const intervalMs = timer.repeatSeconds * 1000;
+  const missed = Math.floor((now - timer.endsAt) / intervalMs) + 1;
+  return timer.endsAt + missed * intervalMs;
+};
+
+const checkTimers = async (
+  publish: (event: TimerExpiredEvent) => void,
+  language: UiLanguage
+) => {
+  const now = Date.now();
+  const store = await loadTimers();
+  const liveKeys = new Set(store.timers.map(deadlineKey));
+  for (const key of [...firedDeadlines]) {
+    if (!liveKeys.has(key)) {
+      firedDeadlines.delete(key);
+    }
+  }
+  for (const timer of store.timers) {
+    if (timer.endsAt > now || firedDeadlines.has(deadlineKey(timer))) {
+      continue;
+    }
+    const nextEndsAt = nextRepeatDeadline(timer, now);
+    if (nextEndsAt === null) {
+      firedDeadlines.add(deadlineKey(timer));
+    } else {
+      await updateTimer(timer.id, { endsAt: nextEndsAt });
+    }
+    publish({
+      timerId: timer.id,
+      label: timer.label,
+      endsAt: timer.endsAt,
+      firedAt: now,
+      nextEndsAt,
+      message: translate(language, "timers.expired", { label: timer.label })
+    });
+  }
+};
+
+// Timers that already expired while the app was closed are not re-announced;
+// repeating ones are silently rolled forward to their next deadline.
+export const startTimerEngine = async (
+  publish: (event: TimerExpiredEvent) => void,
+  getLanguage: () => Promise<UiLanguage>
+) => {
+  if (tickTimer) {
+    return;
+  }
+  const now = Date.now();
+  const store = await loadTimers();
+  for (const timer of store.timers.filter((candidate) => candidate.endsAt <= now)) {
+    const nextEndsAt = nextRepeatDeadline(timer, now);
+    if (nextEndsAt === null) {
+      firedDeadlines.add(deadlineKey(timer));
+    } else {
+      await updateTimer(timer.id, { endsAt: nextEndsAt });
+    }
+  }
+  tickTimer = setInterval(() => {
+    if (tickInFlight) {
+      return;
+    }
+    tickInFlight = true;
+    getLanguage()
+      .then((language) => checkTimers(publish, language))
+      .catch((error: unknown) =>
+        logError("timers.tick.failed", {
+          error: error instanceof Error ? error.message : String(error)
+        })
+      )
+      .finally(() => {
+        tickInFlight = false;
+      });
+  }, TIMER_TICK_MS);
+};
+
+export const stopTimerEngine = () => {
+  if (tickTimer) {
+    clearInterval(tickTimer);
+    tickTimer = null;
+  }
+};
@@ -0,0 +1,115 @@
+import {
+  OverlaySettings,
+  TimersStore,
+  WidgetVisibilityCondition,
+  WidgetVisibilityRule,
+  WidgetVisibilityState
+} from "../shared/ipc";
+import { widgetVisibilityRuleSchema } from "../shared/visibilitySchema";
+import { flattenWidgets, WidgetSpec } from "../widgetSpec";
+import { logError } from "./logging";
+import { loadPlan, loadTimers } from "./storage";
+
+const VISIBILITY_TICK_MS = 1000;
+
+type VisibilityContext = {
+  settings: OverlaySettings;
+  timers: TimersStore;
+  now: number;
+};
+
+type VisibilityEngineOptions = {
+  getSettings: () => Promise<OverlaySettings>;
+  publish: (state: WidgetVisibilityState) => void;
+};
+
+let tickTimer: NodeJS.Timeout | null = null;
+let engineOptions: VisibilityEngineOptions | null = null;
+let lastState: WidgetVisibilityState = {};
+
+const matchesCondition = (condition: WidgetVisibilityCondition, context: VisibilityContext) => {
+  switch (condition.when) {
+    case "captureEnabled":
+      return context.settings.captureEnabled;
+    case "interactive":
+      return context.settings.uiMode !== "gameplay";
+    case "timerRemainingBelow": {
+      const timer = context.timers.timers.find((item) => item.id === condition.timerId);
+      if (!timer) {
+        return false;
+      }
+      const remaining = (timer.endsAt - context.now) / 1000;
+      return remaining > 0 && remaining < condition.seconds;
+    }
+  }
+};
+
+const isVisible = (rule: WidgetVisibilityRule, context: VisibilityContext) =>
+  rule.match === "all"
+    ? rule.conditions.every((condition) => matchesCondition(condition, context))
+    : rule.conditions.some((condition) => matchesCondition(condition, context));
+
+// Only widgets that declare a valid `visibility` rule appear in the state;
+// everything else is always shown.
+export const evaluateWidgetVisibility = (
+  plan: WidgetSpec | null,
+  context: VisibilityContext
+): WidgetVisibilityState => {
+  const state: WidgetVisibilityState = {};
+  flattenWidgets(plan?.widgets ?? []).forEach((widget) => {
+    if (widget.visibility === undefined) {
+      return;
+    }
+    const parsed = widgetVisibilityRuleSchema.safeParse(widget.visibility);
+    if (parsed.success) {
+      state[widget.id] = isVisible(parsed.data, context);
+    }
+  });
+  return state;
+};
+
+const sameState = (a: WidgetVisibilityState, b: WidgetVisibilityState) => {
+  const keys = Object.keys(a);
+  return keys.length === Object.keys(b).length && keys.every((key) => a[key] === b[key]);
+};
+
+export const refreshWidgetVisibility = async (): Promise<WidgetVisibilityState> => {
+  if (!engineOptions) {
+    return lastState;
+  }
+  const [planResult, timers, settings] = await Promise.all([
+    loadPlan(),
+    loadTimers(),
+    engineOptions.getSettings()
+  ]);
+  const next = evaluateWidgetVisibility(planResult.plan, { settings, timers, now: Date.now() });
+  if (!sameState(lastState, next)) {
+    lastState = next;
+    engineOptions.publish(next);
+  }
+  return lastState;
+};
+
+export const getWidgetVisibility = () => lastState;
+
+export const startVisibilityEngine = (options: VisibilityEngineOptions) => {
+  if (tickTimer) {
+    return;
+  }
+  engineOptions = options;
+  tickTimer = setInterval(() => {
+    refreshWidgetVisibility().catch((error: unknown) =>
+      logError("visibility.tick.failed", {
+        error: error instanceof Error ? error.message : String(error)
+      })
+    );
+  }, VISIBILITY_TICK_MS);
+};
+
+export const stopVisibilityEngine = () => {
+  if (tickTimer) {
+    clearInterval(tickTimer);
+    tickTimer = null;
+  }
+  engineOptions = null;
+};
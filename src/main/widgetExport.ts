@@ -0,0 +1,160 @@
+import {
+  CounterEvent,
+  CountersStore,
+  CsvExportResult,
+  EventLog,
+  RulesStore,
+  WidgetExportFormat
+} from "../shared/ipc";
+import { flattenWidgets, WidgetSpec, WidgetSpecWidget } from "../widgetSpec";
+import { CsvCell, isoTime, writeCsv, writeJsonRows } from "./csvExport";
+
+export type WidgetDataSources = {
+  plan: WidgetSpec | null;
+  eventLog: EventLog;
+  counters: CountersStore;
+  rules: RulesStore;
+  counterHistory: (counterId: string, from?: number, to?: number) => Promise<CounterEvent[]>;
+};
+
+type WidgetTable = {
+  header: string[];
+  rows: CsvCell[][];
+};
+
+const inRange = (timestamp: number, from?: number, to?: number) =>
+  (from === undefined || timestamp >= from) && (to === undefined || timestamp <= to);
+
+const readNumber = (value: unknown) =>
+  typeof value === "number" && Number.isFinite(value) ? value : undefined;
+
+const eventTable = (
+  widget: WidgetSpecWidget,
+  eventLog: EventLog,
+  from: number | undefined,
+  to: number | undefined,
+  now: number
+): WidgetTable => {
+  // Rate and projection widgets only look back a fixed window, so that is
+  // what they show when no explicit range is given.
+  const lookback = readNumber(widget.lookbackMinutes);
+  const start = from ?? (lookback !== undefined ? now - lookback * 60 * 1000 : undefined);
+  const entries = eventLog.entries
+    .filter((entry) => entry.eventType === widget.eventType && inRange(entry.timestamp, start, to))
+    .sort((a, b) => a.timestamp - b.timestamp);
+  const showLast = widget.type === "eventLog" ? readNumber(widget.showLast) : undefined;
+  const shown = from === undefined && showLast !== undefined ? entries.slice(-showLast) : entries;
+  return {
+    header: ["timestamp", "time", "event_type", "note", "text"],
+    rows: shown.map((entry) => [
+      entry.timestamp,
+      isoTime(entry.timestamp),
+      entry.eventType,
+      entry.note,
+      entry.data?.text
+    ])
+  };
+};
+
+// Text widgets carry no history of their own; the samples behind them live in
+// the state of whichever trackRate rules write to the widget.
+const rateRuleTable = (
+  widget: WidgetSpecWidget,
+  rules: RulesStore,
+  from?: number,
+  to?: number
+): WidgetTable => {
+  const rows: CsvCell[][] = [];
+  rules.rules.forEach((rule) => {
+    if (rule.action.type !== "trackRate" || rule.action.widgetId !== widget.id) {
+      return;
+    }
+    const samples = rule.state?.samples ?? [];
+    samples.forEach((sample) => rows.push([sample.at, isoTime(sample.at), rule.id, sample.total]));
+    const { lastAt, lastValue } = rule.state ?? {};
+    if (lastAt !== undefined && lastValue !== undefined && !samples.some((s) => s.at === lastAt)) {
+      rows.push([lastAt, isoTime(lastAt), rule.id, lastValue]);
+    }
+  });
+  return {
+    header: ["timestamp", "time", "rule_id", "value"],
+    rows: rows
+      .filter((row) => inRange(row[0] as number, from, to))
+      .sort((a, b) => (a[0] as number) - (b[0] as number))
+  };
+};
+
+const buildWidgetTable = async (
+  widget: WidgetSpecWidget,
+  sources: WidgetDataSources,
+  from: number | undefined,
+  to: number | undefined,
+  now: number
+): Promise<WidgetTable> => {
+  switch (widget.type) {
+    case "eventLog":
+    case "rate":
+    case "projection":
+      return eventTable(widget, sources.eventLog, from, to, now);
+    case "text":
+      return rateRuleTable(widget, sources.rules, from, to);
+    case "counter": {
+      const counter = sources.counters.counters.find((item) => item.id === widget.id);
+      if (counter) {
+        const events = await sources.counterHistory(counter.id, from, to);
+        return {
+          header: ["timestamp", "time", "counter_id", "value", "delta"],
+          rows: events.map((event) => [
+            event.timestamp,
+            isoTime(event.timestamp),
+            event.counterId,
+            event.value,
+            event.delta
+          ])
+        };
+      }
+      return {
+        header: ["timestamp", "time", "widget_id", "value"],
+        rows: [[now, isoTime(now), widget.id, readNumber(widget.value)]]
+      };
+    }
+    case "timer":
+      return {
+        header: ["timestamp", "time", "widget_id", "seconds", "running"],
+        rows: [[now, isoTime(now), widget.id, readNumber(widget.seconds), String(widget.running)]]
+      };
+    case "checklist": {
+      const items = Array.isArray(widget.items)
+        ? (widget.items as Array<{ id?: unknown; text?: unknown; checked?: unknown }>)
+        : [];
+      return {
+        header: ["item_id", "text", "checked"],
+        rows: items.map((item) => [
+          String(item.id ?? ""),
+          String(item.text ?? ""),
+          String(item.checked)
+        ])
+      };
+    }
+    default:
+      throw new Error(`Widget type "${widget.type}" has no exportable data.`);
+  }
+};
+
+export const exportWidgetData = async (
+  path: string,
+  widgetId: string,
+  format: WidgetExportFormat,
+  sources: WidgetDataSources,
+  from?: number,
+  to?: number
+): Promise<CsvExportResult> => {
+  const widget = flattenWidgets(sources.plan?.widgets ?? []).find((item) => item.id === widgetId);
+  if (!widget) {
+    throw new Error(`Widget ${widgetId} not found in the current plan.`);
+  }
+  const table = await buildWidgetTable(widget, sources, from, to, Date.now());
+  return format === "json"
+    ? writeJsonRows(path, table.header, table.rows)
+    : writeCsv(path, table.header, table.rows);
+};
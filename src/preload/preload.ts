@@ -1,17 +1,42 @@
 import { contextBridge, ipcRenderer } from "electron";
 import {
+  AlertNotification,
+  AlertRulesStore,
+  CapturePauseInterval,
+  CaptureRegion,
+  CaptureStatus,
   CaptureSnapshotResult,
   CaptureTarget,
+  CounterCreateInput,
+  CounterGoalEvent,
+  CountersStore,
+  DailyCapsStore,
   EventLog,
+  ExperimentCompareOptions,
+  HotkeyBinding,
+  LogTailBatch,
   MemoryEntry,
   MemoryStore,
+  NoteUpdate,
   OverlayAPI,
   OverlayPlan,
   OverlaySettings,
+  OverlayStateDelta,
+  ProfileImportSummary,
+  PingStats,
   PlannerComposeInput,
   PlannerComposeResult,
   PlanSaveMeta,
-  RulesStore
+  RulesStore,
+  SpectatorSnapshot,
+  SystemStats,
+  TimerCreateInput,
+  TimerExpiredEvent,
+  TimerListOptions,
+  TimersStore,
+  TimerUpdate,
+  WidgetExportFormat,
+  WidgetVisibilityState
 } from "../shared/ipc";
 import type { WidgetSpec } from "../widgetSpec";
 
@@ -48,8 +73,119 @@ const api: OverlayAPI = {
   saveMemory: (store: MemoryStore) => ipcRenderer.invoke("memory:save", store),
   addMemoryEntry: (entry: MemoryEntry) => ipcRenderer.invoke("memory:add", entry),
   deleteMemoryEntry: (entryId: string) => ipcRenderer.invoke("memory:delete", entryId),
+  createNote: (text: string, tags?: string[]) => ipcRenderer.invoke("notes:create", text, tags),
+  listNotes: () => ipcRenderer.invoke("notes:list"),
+  updateNote: (noteId: string, update: NoteUpdate) =>
+    ipcRenderer.invoke("notes:update", noteId, update),
+  deleteNote: (noteId: string) => ipcRenderer.invoke("notes:delete", noteId),
+  pinNote: (noteId: string, pinned: boolean) => ipcRenderer.invoke("notes:pin", noteId, pinned),
+  searchNotes: (query: string) => ipcRenderer.invoke("memory:search-notes", query),
+  globalSearch: (query: string) => ipcRenderer.invoke("search:global", query),
+  captureMonitor: (monitorId: string) => ipcRenderer.invoke("capture:monitor", monitorId),
+  captureRegion: (monitorId: string, region: CaptureRegion) =>
+    ipcRenderer.invoke("capture:region", monitorId, region),
+  captureEventScreenshot: (monitorId: string, label: string, region?: CaptureRegion) =>
+    ipcRenderer.invoke("capture:event-screenshot", monitorId, label, region),
+  getOverlaySnapshot: () => ipcRenderer.invoke("overlay:get-snapshot"),
+  subscribeOverlayState: () => ipcRenderer.invoke("overlay:subscribe-state"),
+  unsubscribeOverlayState: () => ipcRenderer.invoke("overlay:unsubscribe-state"),
+  getWidgetVisibility: () => ipcRenderer.invoke("widgets:visibility"),
+  getServerTime: () => ipcRenderer.invoke("clock:get-server-time"),
+  syncServerClock: () => ipcRenderer.invoke("clock:sync"),
+  getPingStats: () => ipcRenderer.invoke("ping:get-stats"),
+  getPingHistory: (from?: number, to?: number) =>
+    ipcRenderer.invoke("ping:get-history", from, to),
+  getSystemStats: () => ipcRenderer.invoke("system:get-stats"),
+  setLogTailPath: (path: string) => ipcRenderer.invoke("log-tail:set-path", path),
+  getDoNotDisturb: () => ipcRenderer.invoke("notifications:get-dnd"),
+  setDoNotDisturb: (enabled: boolean, until?: number | null) =>
+    ipcRenderer.invoke("notifications:set-dnd", enabled, until),
+  testLogTailPattern: (pattern: string) => ipcRenderer.invoke("log-tail:test-pattern", pattern),
+  loadAlertRules: () => ipcRenderer.invoke("alerts:load"),
+  saveAlertRules: (store: AlertRulesStore) => ipcRenderer.invoke("alerts:save", store),
+  listActions: () => ipcRenderer.invoke("actions:list"),
+  getOverlayClickThrough: (windowLabel?: string) =>
+    ipcRenderer.invoke("overlay:get-clickthrough", windowLabel),
+  setOverlayClickThrough: (windowLabel: string, enabled: boolean) =>
+    ipcRenderer.invoke("overlay:set-clickthrough", windowLabel, enabled),
+  createOverlayWindow: (monitorId: number) =>
+    ipcRenderer.invoke("overlay:create-window", monitorId),
+  closeOverlayWindow: (monitorId: number) => ipcRenderer.invoke("overlay:close-window", monitorId),
+  listOverlayWindows: () => ipcRenderer.invoke("overlay:list-windows"),
+  listHotkeys: () => ipcRenderer.invoke("hotkeys:list"),
+  setHotkey: (binding: HotkeyBinding) => ipcRenderer.invoke("hotkeys:set", binding),
+  deleteHotkey: (bindingId: string) => ipcRenderer.invoke("hotkeys:delete", bindingId),
+  runRetentionNow: () => ipcRenderer.invoke("retention:run-now"),
+  getSpectatorSnapshot: () => ipcRenderer.invoke("spectator:get-snapshot"),
+  onSpectatorSnapshot: (callback: (snapshot: SpectatorSnapshot) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, snapshot: SpectatorSnapshot) =>
+      callback(snapshot);
+    ipcRenderer.on("spectator:snapshot", listener);
+    return () => ipcRenderer.removeListener("spectator:snapshot", listener);
+  },
+  getLanguageCatalog: () => ipcRenderer.invoke("i18n:get-catalog"),
+  loadDailyCaps: () => ipcRenderer.invoke("caps:load"),
+  saveDailyCaps: (store: DailyCapsStore) => ipcRenderer.invoke("caps:save", store),
+  getDailyCapStatuses: () => ipcRenderer.invoke("caps:status"),
+  getCompatibilityReport: (refresh?: boolean) =>
+    ipcRenderer.invoke("compatibility:report", refresh),
+  backupProfile: (destPath: string) => ipcRenderer.invoke("profile:backup", destPath),
+  restoreProfile: (srcPath: string) => ipcRenderer.invoke("profile:restore", srcPath),
+  getStagedProfileImport: () => ipcRenderer.invoke("profile:get-staged-import"),
+  applyStagedProfileImport: () => ipcRenderer.invoke("profile:apply-staged-import"),
+  discardStagedProfileImport: () => ipcRenderer.invoke("profile:discard-staged-import"),
+  importExpTable: (name: string, content: string) =>
+    ipcRenderer.invoke("exp-tables:import", name, content),
+  listExpTables: () => ipcRenderer.invoke("exp-tables:list"),
+  setActiveExpTable: (tableId: string | null) =>
+    ipcRenderer.invoke("exp-tables:set-active", tableId),
+  getActiveExpTable: () => ipcRenderer.invoke("exp-tables:get-active"),
+  listExperiments: () => ipcRenderer.invoke("experiments:list"),
+  markExperiment: (label: string, note?: string) =>
+    ipcRenderer.invoke("experiments:mark", label, note),
+  deleteExperiment: (label: string) => ipcRenderer.invoke("experiments:delete", label),
+  compareExperiment: (label: string, options: ExperimentCompareOptions) =>
+    ipcRenderer.invoke("experiments:compare", label, options),
+  executeAction: (actionId: string, args?: Record<string, unknown>) =>
+    ipcRenderer.invoke("actions:execute", actionId, args),
   loadRules: () => ipcRenderer.invoke("rules:load"),
   saveRules: (store: RulesStore) => ipcRenderer.invoke("rules:save", store),
+  loadTimers: () => ipcRenderer.invoke("timers:load"),
+  saveTimers: (store: TimersStore) => ipcRenderer.invoke("timers:save", store),
+  listTimers: (options?: Partial<TimerListOptions>) => ipcRenderer.invoke("timers:list", options),
+  setTimerListOptions: (options: TimerListOptions) =>
+    ipcRenderer.invoke("timers:set-list-options", options),
+  createTimer: (input: TimerCreateInput) => ipcRenderer.invoke("timers:create", input),
+  updateTimer: (timerId: string, update: TimerUpdate) =>
+    ipcRenderer.invoke("timers:update", timerId, update),
+  deleteTimer: (timerId: string) => ipcRenderer.invoke("timers:delete", timerId),
+  extendTimer: (timerId: string, seconds: number) =>
+    ipcRenderer.invoke("timers:extend", timerId, seconds),
+  loadCounters: () => ipcRenderer.invoke("counters:load"),
+  saveCounters: (store: CountersStore) => ipcRenderer.invoke("counters:save", store),
+  listCounters: () => ipcRenderer.invoke("counters:list"),
+  createCounter: (input: CounterCreateInput) => ipcRenderer.invoke("counters:create", input),
+  incrementCounter: (counterId: string, delta: number) =>
+    ipcRenderer.invoke("counters:increment", counterId, delta),
+  applyCounterInput: (counterId: string, input: string) =>
+    ipcRenderer.invoke("counters:apply-input", counterId, input),
+  exportCounterHistoryCsv: (path: string, counterId?: string, from?: number, to?: number) =>
+    ipcRenderer.invoke("export:counter-history-csv", path, counterId, from, to),
+  exportEventLogCsv: (path: string, from?: number, to?: number) =>
+    ipcRenderer.invoke("export:event-log-csv", path, from, to),
+  exportWidgetData: (
+    path: string,
+    widgetId: string,
+    format: WidgetExportFormat,
+    from?: number,
+    to?: number
+  ) => ipcRenderer.invoke("export:widget-data", path, widgetId, format, from, to),
+  resetCounter: (counterId: string) => ipcRenderer.invoke("counters:reset", counterId),
+  deleteCounter: (counterId: string) => ipcRenderer.invoke("counters:delete", counterId),
+  stepCounter: (counterId: string, direction: 1 | -1) =>
+    ipcRenderer.invoke("counters:step", counterId, direction),
+  getCounterHistory: (counterId: string, from?: number, to?: number) =>
+    ipcRenderer.invoke("counters:history", counterId, from, to),
   listCaptureSources: async () => {
     try {
       return await ipcRenderer.invoke("capture:list-sources");
@@ -77,10 +213,109 @@ const api: OverlayAPI = {
   stopCapture: () => {
     // Main-process capture is stateless; nothing to stop.
   },
+  getCaptureStatus: () => ipcRenderer.invoke("capture:get-status"),
+  pauseCapture: (reason?: string) => ipcRenderer.invoke("capture:pause", reason),
+  resumeCapture: () => ipcRenderer.invoke("capture:resume"),
+  onCaptureStatusChanged: (callback: (status: CaptureStatus) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, status: CaptureStatus) =>
+      callback(status);
+    ipcRenderer.on("capture:status-changed", listener);
+    return () => ipcRenderer.removeListener("capture:status-changed", listener);
+  },
+  onCapturePauseEnded: (callback: (interval: CapturePauseInterval) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, interval: CapturePauseInterval) =>
+      callback(interval);
+    ipcRenderer.on("capture:pause-ended", listener);
+    return () => ipcRenderer.removeListener("capture:pause-ended", listener);
+  },
+  onCaptureTick: (callback: (at: number) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, at: number) => callback(at);
+    ipcRenderer.on("capture:tick", listener);
+    return () => ipcRenderer.removeListener("capture:tick", listener);
+  },
   onEscapeHatch: (callback: () => void) => {
     const listener = () => callback();
     ipcRenderer.on("app:escape-hatch", listener);
     return () => ipcRenderer.removeListener("app:escape-hatch", listener);
+  },
+  onBeforeQuit: (callback: () => Promise<void> | void) => {
+    const listener = async () => {
+      try {
+        await callback();
+      } finally {
+        ipcRenderer.send("app:shutdown-ready");
+      }
+    };
+    ipcRenderer.on("app:before-quit", listener);
+    return () => ipcRenderer.removeListener("app:before-quit", listener);
+  },
+  onTimerExpired: (callback: (event: TimerExpiredEvent) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, event: TimerExpiredEvent) =>
+      callback(event);
+    ipcRenderer.on("timers:expired", listener);
+    return () => ipcRenderer.removeListener("timers:expired", listener);
+  },
+  onCounterGoalReached: (callback: (event: CounterGoalEvent) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, goal: CounterGoalEvent) => callback(goal);
+    ipcRenderer.on("counters:goal-reached", listener);
+    return () => ipcRenderer.removeListener("counters:goal-reached", listener);
+  },
+  onSettingsChanged: (callback: (settings: OverlaySettings) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, settings: OverlaySettings) =>
+      callback(settings);
+    ipcRenderer.on("app:settings-changed", listener);
+    return () => ipcRenderer.removeListener("app:settings-changed", listener);
+  },
+  onPlanChanged: (callback: (plan: WidgetSpec) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, plan: WidgetSpec) => callback(plan);
+    ipcRenderer.on("plan:changed", listener);
+    return () => ipcRenderer.removeListener("plan:changed", listener);
+  },
+  onEventLogChanged: (callback: (log: EventLog) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, log: EventLog) => callback(log);
+    ipcRenderer.on("event-log:changed", listener);
+    return () => ipcRenderer.removeListener("event-log:changed", listener);
+  },
+  onProfileImportStaged: (callback: (summary: ProfileImportSummary) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, summary: ProfileImportSummary) =>
+      callback(summary);
+    ipcRenderer.on("profile:import-staged", listener);
+    return () => ipcRenderer.removeListener("profile:import-staged", listener);
+  },
+  onAlertFired: (callback: (notification: AlertNotification) => void) => {
+    const listener = (
+      _event: Electron.IpcRendererEvent,
+      notification: AlertNotification
+    ) => callback(notification);
+    ipcRenderer.on("alerts:fired", listener);
+    return () => ipcRenderer.removeListener("alerts:fired", listener);
+  },
+  onLogTailLines: (callback: (batch: LogTailBatch) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, batch: LogTailBatch) => callback(batch);
+    ipcRenderer.on("log-tail:lines", listener);
+    return () => ipcRenderer.removeListener("log-tail:lines", listener);
+  },
+  onOverlayStateDelta: (callback: (delta: OverlayStateDelta) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, delta: OverlayStateDelta) =>
+      callback(delta);
+    ipcRenderer.on("overlay:state-delta", listener);
+    return () => ipcRenderer.removeListener("overlay:state-delta", listener);
+  },
+  onPingSample: (callback: (stats: PingStats) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, stats: PingStats) => callback(stats);
+    ipcRenderer.on("ping:sample", listener);
+    return () => ipcRenderer.removeListener("ping:sample", listener);
+  },
+  onSystemStats: (callback: (stats: SystemStats) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, stats: SystemStats) => callback(stats);
+    ipcRenderer.on("system:stats", listener);
+    return () => ipcRenderer.removeListener("system:stats", listener);
+  },
+  onWidgetVisibilityChanged: (callback: (state: WidgetVisibilityState) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, state: WidgetVisibilityState) =>
+      callback(state);
+    ipcRenderer.on("widgets:visibility-changed", listener);
+    return () => ipcRenderer.removeListener("widgets:visibility-changed", listener);
   }
 };
 
@@ -11,20 +11,33 @@ import {
   LlmProvider,
   LlmSettings,
   MemoryEntry,
+  NumberLocale,
   MemoryStore,
   OverlayPlan,
   OverlaySettings,
   Rule,
+  RuleState,
   RulesStore,
   TextWidget,
-  OverlayWidget
+  OverlayWidget,
+  ProfileImportSummary,
+  SpectatorSettings,
+  UiLanguage,
+  WidgetVisibilityState
 } from "../shared/ipc";
+import { parseNumericValue } from "../shared/numberParsing";
+import { smoothValue } from "../shared/valueSmoothing";
+import { accumulateGain } from "../shared/gainTotals";
+import { computeSmoothedRate } from "../shared/rateSmoothing";
+import { cumulativeExpAt, toAbsoluteExp } from "../shared/expTables";
 import { runPlanValidations } from "../shared/planValidation";
+import { defaultSettings } from "../shared/settingsSchema";
 import { buildPlanFromChat } from "../builder/widgetBuilderEngine";
 import { Question } from "../builder/questions";
 import { WidgetSpec } from "../widgetSpec";
 import { defaultPlan, plannerStub } from "./planner";
 import PlanRenderer from "./PlanRenderer";
+import { useOverlayState } from "./overlayState";
 import { ChatComposer } from "../components/Composer/ChatComposer";
 import {
   applyPlan,
@@ -33,26 +46,7 @@ import {
   widgetSpecToOverlayPlan
 } from "../state/planStore";
 
-const fallbackSettings: OverlaySettings = {
-  bounds: null,
-  displayId: null,
-  opacity: 0.92,
-  clickThrough: false,
-  captureEnabled: false,
-  captureSourceType: null,
-  captureSourceId: null,
-  captureRoi: null,
-  uiMode: "gameplay",
-  llm: {
-    enabled: false,
-    provider: "ollama",
-    baseUrl: "http://127.0.0.1:11434/v1",
-    model: "llama3.2:1b",
-    apiKey: ""
-  }
-};
-
-const CAPTURE_INTERVAL_MS = 15000;
+const PAUSE_REASONS = ["break", "trading", "boss run", "other"];
 const OCR_TEXT_LIMIT = 2000;
 const OCR_PREVIEW_LIMIT = 140;
 
@@ -97,6 +91,25 @@ const buildEntryId = () => {
   return `event-${Date.now()}-${suffix}`;
 };
 
+const playAlertTone = (critical: boolean) => {
+  try {
+    const context = new AudioContext();
+    const oscillator = context.createOscillator();
+    const gain = context.createGain();
+    oscillator.frequency.value = critical ? 880 : 660;
+    gain.gain.value = 0.15;
+    oscillator.connect(gain);
+    gain.connect(context.destination);
+    oscillator.start();
+    oscillator.stop(context.currentTime + (critical ? 0.6 : 0.25));
+    oscillator.onended = () => {
+      context.close().catch(() => undefined);
+    };
+  } catch {
+    // Audio is best-effort; the alert is still logged.
+  }
+};
+
 const buildRuleId = () => {
   const suffix = Math.random().toString(36).slice(2, 8);
   return `rule-${Date.now()}-${suffix}`;
@@ -119,45 +132,46 @@ const flattenWidgets = (widgets: OverlayWidget[]): OverlayWidget[] => {
   return result;
 };
 
+const filterHiddenWidgets = (
+  widgets: OverlayWidget[],
+  visibility: WidgetVisibilityState
+): OverlayWidget[] =>
+  widgets
+    .filter((widget) => visibility[widget.id] !== false)
+    .map((widget) =>
+      widget.type === "panel"
+        ? { ...widget, children: filterHiddenWidgets(widget.children, visibility) }
+        : widget
+    );
+
 const plansEqual = (a: OverlayPlan | null, b: OverlayPlan | null) =>
   Boolean(a && b && JSON.stringify(a) === JSON.stringify(b));
 
-const parseNumericValue = (raw: string): number | null => {
-  const cleaned = raw.replace(/[^0-9,.-]/g, "");
-  if (!cleaned) {
-    return null;
-  }
-  const hasComma = cleaned.includes(",");
-  const hasDot = cleaned.includes(".");
-  let normalized = cleaned;
-  if (hasComma && hasDot) {
-    const lastComma = cleaned.lastIndexOf(",");
-    const lastDot = cleaned.lastIndexOf(".");
-    const decimalIndex = Math.max(lastComma, lastDot);
-    const integerPart = cleaned.slice(0, decimalIndex).replace(/[.,]/g, "");
-    const decimalPart = cleaned.slice(decimalIndex + 1);
-    normalized = `${integerPart}.${decimalPart}`;
-  } else if (hasComma || hasDot) {
-    const sep = hasComma ? "," : ".";
-    const parts = cleaned.split(sep);
-    const last = parts[parts.length - 1] ?? "";
-    if (last.length === 3 && parts.length > 1) {
-      normalized = parts.join("");
-    } else {
-      normalized = `${parts.slice(0, -1).join("")}.${last}`;
-    }
+const formatEta = (seconds: number | null) => {
+  if (seconds === null || !Number.isFinite(seconds) || seconds < 0) {
+    return "n/a";
   }
-  const value = Number(normalized);
-  return Number.isFinite(value) ? value : null;
+  const totalMinutes = Math.ceil(seconds / 60);
+  const hours = Math.floor(totalMinutes / 60);
+  const minutes = totalMinutes % 60;
+  return hours > 0 ? `${hours}h ${minutes}m` : `${minutes}m`;
 };
 
-const formatRateTemplate = (template: string, rate: number, unit: string, value: number, precision: number) => {
+const formatRateTemplate = (
+  template: string,
+  rate: number,
+  unit: string,
+  value: number,
+  precision: number,
+  etaSeconds: number | null = null
+) => {
   const rateText = rate.toFixed(precision);
   const valueText = value.toFixed(precision);
   return template
     .replace(/\$\{rate\}/g, rateText)
     .replace(/\$\{unit\}/g, unit)
-    .replace(/\$\{value\}/g, valueText);
+    .replace(/\$\{value\}/g, valueText)
+    .replace(/\$\{eta\}/g, formatEta(etaSeconds));
 };
 
 const normalizePassiveText = (text: string) => text.replace(/\s+/g, " ").trim();
@@ -215,6 +229,7 @@ const App = () => {
   const [, setPlanLoadError] = useState<string | null>(null);
   const [planError, setPlanError] = useState<string | null>(null);
   const [planWarning, setPlanWarning] = useState<string | null>(null);
+  const overlayState = useOverlayState();
   const [eventLog, setEventLog] = useState<EventLog>(emptyEventLog);
   const [eventLogError, setEventLogError] = useState<string | null>(null);
   const [memoryStore, setMemoryStore] = useState<MemoryStore>(emptyMemory);
@@ -254,6 +269,13 @@ const App = () => {
   const defaultPlanMemo = useMemo(() => defaultPlan(), []);
   const captureInFlightRef = useRef(false);
   const skipNextCaptureRef = useRef(false);
+  const capturePausedRef = useRef(false);
+  // Latest rules for subscriptions that outlive a render; persisting must not
+  // happen inside a state updater, which React may call more than once.
+  const rulesStoreRef = useRef(rulesStore);
+  rulesStoreRef.current = rulesStore;
+  const [capturePaused, setCapturePaused] = useState(false);
+  const [pauseReason, setPauseReason] = useState(PAUSE_REASONS[0]);
   const roiImageRef = useRef<HTMLImageElement | null>(null);
   const roiDragRef = useRef<{ active: boolean; startX: number; startY: number }>({
     active: false,
@@ -283,7 +305,7 @@ const App = () => {
     const bootstrap = async () => {
       if (!overlayAPI) {
         setPlannerNote("Preload bridge not loaded. Restart dev server after rebuilding Electron.");
-        setSettings(fallbackSettings);
+        setSettings(defaultSettings);
         setPlan(defaultPlanMemo);
         setEventLog(emptyEventLog);
         return;
@@ -299,7 +321,7 @@ const App = () => {
       if (settingsResult.status === "fulfilled") {
         setSettings(settingsResult.value);
       } else {
-        setSettings(fallbackSettings);
+        setSettings(defaultSettings);
         setPlannerNote(
           `Failed to load settings. ${settingsResult.reason instanceof Error ? settingsResult.reason.message : ""}`.trim()
         );
@@ -417,7 +439,7 @@ const App = () => {
       }
     };
     bootstrap().catch((error: unknown) => {
-      setSettings(fallbackSettings);
+      setSettings(defaultSettings);
       const fallbackSpec = buildFallbackWidgetSpec(
         PROFILE_ID,
         "Bootstrap failed. Created a minimal WidgetSpec plan."
@@ -455,6 +477,79 @@ const App = () => {
     });
   }, [overlayAPI]);
 
+  useEffect(() => {
+    if (!overlayAPI || typeof overlayAPI.onSettingsChanged !== "function") {
+      return;
+    }
+    return overlayAPI.onSettingsChanged((next) => {
+      setSettings(next);
+    });
+  }, [overlayAPI]);
+
+  useEffect(() => {
+    if (!overlayAPI || typeof overlayAPI.onPlanChanged !== "function") {
+      return;
+    }
+    return overlayAPI.onPlanChanged((next) => {
+      const overlayPlan = widgetSpecToOverlayPlan(next);
+      setWidgetSpecPlan(next);
+      setLastKnownGoodWidgetSpec(next);
+      setPlan(overlayPlan);
+      setLastValidPlan(overlayPlan);
+      setPlanError(null);
+    });
+  }, [overlayAPI]);
+
+  useEffect(() => {
+    if (!overlayAPI || typeof overlayAPI.onProfileImportStaged !== "function") {
+      return;
+    }
+    const reviewImport = (summary: ProfileImportSummary) => {
+      const confirmed = window.confirm(
+        `Import shared profile "${summary.name}"?\n` +
+          `${summary.widgetCount} widgets, ${summary.ruleCount} rules from ${summary.sourceUrl}.\n` +
+          "This replaces the current plan."
+      );
+      if (!confirmed) {
+        overlayAPI.discardStagedProfileImport().catch(() => undefined);
+        return;
+      }
+      overlayAPI
+        .applyStagedProfileImport()
+        .then((result) => {
+          if (result.rules) {
+            setRulesStore(result.rules);
+          }
+          setPlannerNote(`Imported profile "${summary.name}".`);
+        })
+        .catch((error: unknown) => {
+          setPlannerNote(error instanceof Error ? error.message : "Profile import failed.");
+        });
+    };
+    overlayAPI
+      .getStagedProfileImport()
+      .then((summary) => summary && reviewImport(summary))
+      .catch(() => undefined);
+    return overlayAPI.onProfileImportStaged(reviewImport);
+  }, [overlayAPI]);
+
+  useEffect(() => {
+    if (!overlayAPI || typeof overlayAPI.getCompatibilityReport !== "function") {
+      return;
+    }
+    overlayAPI
+      .getCompatibilityReport()
+      .then((report) => {
+        if (report.findings.length === 0) {
+          return;
+        }
+        const detected = report.findings.map((finding) => finding.label).join(", ");
+        const advice = report.suggestions.map((suggestion) => suggestion.description).join(" ");
+        setPlannerNote(`Detected ${detected}. ${advice}`);
+      })
+      .catch(() => undefined);
+  }, [overlayAPI]);
+
   useEffect(() => {
     if (!overlayAPI) {
       return;
@@ -485,6 +580,14 @@ const App = () => {
     [lastValidPlan, plan, defaultPlanMemo]
   );
 
+  const visiblePlan = useMemo(
+    () => ({
+      ...activePlan,
+      widgets: filterHiddenWidgets(activePlan.widgets, overlayState?.widgetVisibility ?? {})
+    }),
+    [activePlan, overlayState?.widgetVisibility]
+  );
+
   const saveSettings = async (next: OverlaySettings) => {
     setSettings(next);
     if (!overlayAPI) {
@@ -587,6 +690,38 @@ const App = () => {
     }
   };
 
+  const handleDoNotDisturbToggle = () => {
+    if (!overlayAPI || !settings) {
+      return;
+    }
+    overlayAPI.setDoNotDisturb(!settings.doNotDisturb.enabled).catch(() => undefined);
+  };
+
+  const handleNumberLocaleChange = (event: React.ChangeEvent<HTMLSelectElement>) => {
+    if (settings) {
+      saveSettings({ ...settings, numberLocale: event.target.value as NumberLocale });
+    }
+  };
+
+  const handleLanguageChange = (event: React.ChangeEvent<HTMLSelectElement>) => {
+    if (settings) {
+      saveSettings({ ...settings, language: event.target.value as UiLanguage });
+    }
+  };
+
+  const updateSpectatorSettings = (next: Partial<SpectatorSettings>) => {
+    if (settings) {
+      saveSettings({ ...settings, spectator: { ...settings.spectator, ...next } });
+    }
+  };
+
+  // Main reloads the overlay into the read-only spectator view once saved.
+  const handleStartSpectating = () => {
+    if (settings?.spectator.url.trim()) {
+      saveSettings({ ...settings, spectator: { ...settings.spectator, enabled: true } });
+    }
+  };
+
   const handleModeChange = (next: UiMode) => {
     if (!settings) {
       return;
@@ -853,6 +988,18 @@ const App = () => {
     [overlayAPI]
   );
 
+  useEffect(() => {
+    if (!overlayAPI || typeof overlayAPI.onBeforeQuit !== "function") {
+      return;
+    }
+    return overlayAPI.onBeforeQuit(async () => {
+      // Stop the capture loop locally; main already refuses new work.
+      setSettings((prev) => (prev ? { ...prev, captureEnabled: false } : prev));
+      await persistEventLog(eventLog);
+      await persistRules(rulesStore);
+    });
+  }, [eventLog, overlayAPI, persistEventLog, persistRules, rulesStore]);
+
   const handleAddEventEntry = useCallback((entry: EventLogEntry) => {
     setEventLog((prev) => {
       const next = { ...prev, entries: [...prev.entries, entry] };
@@ -861,6 +1008,52 @@ const App = () => {
     });
   }, [persistEventLog]);
 
+  useEffect(() => {
+    if (!overlayAPI || typeof overlayAPI.onCounterGoalReached !== "function") {
+      return;
+    }
+    return overlayAPI.onCounterGoalReached((goal) => {
+      handleAddEventEntry({
+        id: buildEntryId(),
+        eventType: "counter-goal",
+        timestamp: goal.reachedAt,
+        note: goal.message
+      });
+    });
+  }, [handleAddEventEntry, overlayAPI]);
+
+  useEffect(() => {
+    if (!overlayAPI || typeof overlayAPI.onTimerExpired !== "function") {
+      return;
+    }
+    return overlayAPI.onTimerExpired((event) => {
+      handleAddEventEntry({
+        id: buildEntryId(),
+        eventType: "timer-expired",
+        timestamp: event.firedAt,
+        note: event.message
+      });
+    });
+  }, [handleAddEventEntry, overlayAPI]);
+
+  useEffect(() => {
+    if (!overlayAPI || typeof overlayAPI.onAlertFired !== "function") {
+      return;
+    }
+    return overlayAPI.onAlertFired(({ event, sound }) => {
+      if (sound) {
+        playAlertTone(event.critical);
+      }
+      handleAddEventEntry({
+        id: buildEntryId(),
+        eventType: "alert",
+        timestamp: event.firedAt,
+        note: event.message,
+        ...(event.screenshotPath ? { data: { capturePath: event.screenshotPath } } : {})
+      });
+    });
+  }, [handleAddEventEntry, overlayAPI]);
+
   const handleAddMemoryEntry = useCallback(
     (text: string) => {
       const trimmed = text.trim();
@@ -975,7 +1168,13 @@ const App = () => {
     [persistRules]
   );
 
-  type PassiveInput = { source: "ocr" | "manual_event"; text: string; timestamp: number };
+  type PassiveInput = {
+    source: "ocr" | "manual_event" | "log";
+    text: string;
+    timestamp: number;
+  };
+
+  const numberLocale = settings?.numberLocale ?? "auto";
 
   const applyRulesFromPassiveInput = useCallback(
     async (input: PassiveInput) => {
@@ -991,6 +1190,13 @@ const App = () => {
       if (enabledRules.length === 0) {
         return;
       }
+      const needsExpTable = enabledRules.some(
+        (rule) => rule.action.type === "trackRate" && rule.action.levelSource
+      );
+      const expTable =
+        needsExpTable && typeof overlayAPI.getActiveExpTable === "function"
+          ? await overlayAPI.getActiveExpTable().catch(() => null)
+          : null;
 
       const interpolate = (template: string, match0: string, groups: string[]) => {
         return template
@@ -1022,6 +1228,9 @@ const App = () => {
 
       let nextPlan: OverlayPlan = plan;
       const fired: Rule[] = [];
+      const wentIdle: Rule[] = [];
+      const leveledUp: Rule[] = [];
+      const missingLevels: Array<{ rule: Rule; level: string; table: string }> = [];
       let rulesChanged = false;
 
       const updatedRules = rulesStore.rules.map((rule) => {
@@ -1080,37 +1289,153 @@ const App = () => {
         if (rule.action.type === "trackRate" && target.type === "text") {
           const valueSource = rule.action.valueSource ?? "match0";
           const valueRaw = valueSource === "g1" ? groups[0] ?? match0 : match0;
-          const currentValue = parseNumericValue(valueRaw);
+          const parsedValue = parseNumericValue(valueRaw, numberLocale);
+          const parsedLevel = Number.parseInt(groups[1] ?? "", 10);
+          const level = Number.isNaN(parsedLevel) ? null : parsedLevel;
+          // With a level group the parsed value is a percent into that level;
+          // the active exp table turns it into absolute exp. A level the
+          // table cannot resolve falls back to the raw value for this tick.
+          const absoluteValue =
+            parsedValue !== null && rule.action.levelSource && expTable && level !== null
+              ? toAbsoluteExp(expTable, level, parsedValue)
+              : null;
+          const missingLevel =
+            parsedValue !== null && rule.action.levelSource && expTable && absoluteValue === null
+              ? groups[1] ?? ""
+              : undefined;
+          if (expTable && missingLevel !== undefined && rule.state?.missingLevel !== missingLevel) {
+            missingLevels.push({ rule, level: missingLevel, table: expTable.name });
+          }
+          const currentValue = rule.action.levelSource
+            ? expTable
+              ? absoluteValue ?? parsedValue
+              : null
+            : parsedValue;
+          const levelTarget = rule.action.levelSource
+            ? expTable && absoluteValue !== null && level !== null
+              ? cumulativeExpAt(expTable, level + 1)
+              : null
+            : rule.action.rolloverAt ?? null;
           if (currentValue === null) {
             return rule;
           }
+          const smoothed = smoothValue(currentValue, rule.state, rule.action.smoothing);
+          if (!smoothed.accepted) {
+            if (
+              rule.state?.pendingValue === smoothed.pendingValue &&
+              rule.state?.pendingCount === smoothed.pendingCount
+            ) {
+              return rule;
+            }
+            rulesChanged = true;
+            return {
+              ...rule,
+              state: {
+                ...rule.state,
+                pendingValue: smoothed.pendingValue,
+                pendingCount: smoothed.pendingCount
+              }
+            };
+          }
 
           const previousValue = rule.state?.lastValue;
           const previousAt = rule.state?.lastAt;
           const minSeconds = rule.action.minSeconds ?? 60;
           const precision = rule.action.precision ?? 2;
           const unit = rule.action.unit ?? "";
+          let rateState: Pick<RuleState, "smoothedRate" | "samples"> = {
+            smoothedRate: rule.state?.smoothedRate,
+            samples: rule.state?.samples
+          };
+          let lastRate = rule.state?.lastRate;
+          let idleTicks = rule.state?.idleTicks ?? 0;
+          const idleAfter = rule.action.idleAfterTicks;
+          // A drop on a rollover rule is a level-up: count the rest of the
+          // previous level plus progress into the new one.
+          const rolloverAt = rule.action.rolloverAt;
+          const gainedSinceLast =
+            previousValue === undefined
+              ? 0
+              : rolloverAt !== undefined && currentValue < previousValue
+                ? rolloverAt - previousValue + currentValue
+                : currentValue - previousValue;
+          const levelStart =
+            rule.action.levelSource && expTable && level !== null
+              ? cumulativeExpAt(expTable, level)
+              : null;
+          if (
+            previousValue !== undefined &&
+            (rolloverAt !== undefined
+              ? currentValue < previousValue
+              : levelStart !== null && previousValue < levelStart && currentValue >= levelStart)
+          ) {
+            leveledUp.push(rule);
+          }
+          const gainTotals = accumulateGain(
+            rule.state,
+            gainedSinceLast,
+            capturedAt,
+            idleAfter !== undefined && idleTicks >= idleAfter
+          );
 
           if (previousValue !== undefined && previousAt !== undefined) {
             const deltaMs = capturedAt - previousAt;
             if (deltaMs >= minSeconds * 1000 && deltaMs > 0) {
-              const rate = (currentValue - previousValue) / (deltaMs / 3600000);
-              const text = formatRateTemplate(
-                rule.action.template,
-                rate,
-                unit,
-                currentValue,
-                precision
-              );
-              const updated: TextWidget = { ...target, text };
-              nextPlan = { ...nextPlan, widgets: updateWidgetById(nextPlan.widgets, updated) };
-              fired.push(rule);
+              idleTicks = idleAfter !== undefined && gainedSinceLast === 0 ? idleTicks + 1 : 0;
+              if (idleAfter !== undefined && idleTicks >= idleAfter) {
+                // Idle stretches are cut out of the rate: the displayed value is
+                // held and regression history slides forward over the gap.
+                if (idleTicks === idleAfter) {
+                  wentIdle.push(rule);
+                }
+                rateState = {
+                  ...rateState,
+                  samples: rateState.samples?.map((sample) => ({
+                    ...sample,
+                    at: sample.at + deltaMs
+                  }))
+                };
+              } else {
+                const { rate, ...nextRateState } = computeSmoothedRate(
+                  rule.action,
+                  rule.state,
+                  { from: previousAt, to: capturedAt, gained: gainedSinceLast }
+                );
+                rateState = nextRateState;
+                lastRate = rate;
+                const etaSeconds =
+                  levelTarget !== null && rate > 0
+                    ? ((levelTarget - currentValue) / rate) * 3600
+                    : null;
+                const text = formatRateTemplate(
+                  rule.action.template,
+                  rate,
+                  unit,
+                  currentValue,
+                  precision,
+                  etaSeconds
+                );
+                const updated: TextWidget = { ...target, text };
+                nextPlan = { ...nextPlan, widgets: updateWidgetById(nextPlan.widgets, updated) };
+                fired.push(rule);
+              }
             }
           }
 
-          const nextState = { lastValue: currentValue, lastAt: capturedAt };
+          const nextState: RuleState = {
+            lastValue: currentValue,
+            lastAt: capturedAt,
+            ...(rateState.smoothedRate !== undefined ? { smoothedRate: rateState.smoothedRate } : {}),
+            ...(lastRate !== undefined ? { lastRate } : {}),
+            ...(rateState.samples ? { samples: rateState.samples } : {}),
+            ...(idleTicks > 0 ? { idleTicks } : {}),
+            ...(missingLevel !== undefined ? { missingLevel } : {}),
+            ...gainTotals
+          };
           const stateChanged =
-            rule.state?.lastValue !== nextState.lastValue || rule.state?.lastAt !== nextState.lastAt;
+            rule.state?.lastValue !== nextState.lastValue ||
+            rule.state?.lastAt !== nextState.lastAt ||
+            rule.state?.pendingValue !== undefined;
           if (!stateChanged) {
             return rule;
           }
@@ -1127,6 +1452,44 @@ const App = () => {
         persistRules(nextRulesStore).catch(() => undefined);
       }
 
+      missingLevels.forEach(({ rule, level, table }) => {
+        handleAddEventEntry({
+          id: buildEntryId(),
+          eventType: "rule",
+          timestamp: capturedAt,
+          note: `Level "${level}" for ${rule.id} is not in exp table "${table}"; using the raw value.`
+        });
+      });
+
+      wentIdle.forEach((rule) => {
+        handleAddEventEntry({
+          id: buildEntryId(),
+          eventType: "afk",
+          timestamp: capturedAt,
+          note: `No progress on ${rule.id}; rate paused until it moves again.`
+        });
+      });
+
+      leveledUp.forEach((rule) => {
+        const logLevelUp = (capturePath?: string) =>
+          handleAddEventEntry({
+            id: buildEntryId(),
+            eventType: "level-up",
+            timestamp: capturedAt,
+            note: `Level up on ${rule.id}`,
+            ...(capturePath ? { data: { capturePath } } : {})
+          });
+        const screenshot =
+          rule.action.type === "trackRate" ? rule.action.screenshotOnLevelUp : undefined;
+        if (!screenshot || typeof overlayAPI.captureEventScreenshot !== "function") {
+          logLevelUp();
+          return;
+        }
+        overlayAPI
+          .captureEventScreenshot(screenshot.monitorId, `level-up-${rule.id}`, screenshot.region)
+          .then(logLevelUp, () => logLevelUp());
+      });
+
       if (!fired.length || plansEqual(plan, nextPlan)) {
         return;
       }
@@ -1159,7 +1522,15 @@ const App = () => {
         // If save fails, don't surface it as OCR error.
       }
     },
-    [handleAddEventEntry, overlayAPI, persistRules, plan, rulesStore, widgetSpecPlan]
+    [
+      handleAddEventEntry,
+      numberLocale,
+      overlayAPI,
+      persistRules,
+      plan,
+      rulesStore,
+      widgetSpecPlan
+    ]
   );
 
   const handleAddManualEventEntry = useCallback(
@@ -1178,9 +1549,23 @@ const App = () => {
     [applyRulesFromPassiveInput, handleAddEventEntry]
   );
 
+  useEffect(() => {
+    if (!overlayAPI || typeof overlayAPI.onLogTailLines !== "function") {
+      return;
+    }
+    return overlayAPI.onLogTailLines((batch) => {
+      // One rules pass per batch, newest line first so regex rules see the latest value.
+      applyRulesFromPassiveInput({
+        source: "log",
+        text: [...batch.lines].reverse().join("\n"),
+        timestamp: batch.readAt
+      }).catch(() => undefined);
+    });
+  }, [applyRulesFromPassiveInput, overlayAPI]);
+
   const captureOnce = useCallback(
     async (target: { id: string; type: CaptureSourceType } | null) => {
-      if (captureInFlightRef.current) {
+      if (captureInFlightRef.current || capturePausedRef.current) {
         return;
       }
       if (!overlayAPI || typeof overlayAPI.captureAndProcess !== "function") {
@@ -1246,8 +1631,74 @@ const App = () => {
     [applyRulesFromPassiveInput, handleAddEventEntry, overlayAPI]
   );
 
+  useEffect(() => {
+    if (!overlayAPI || typeof overlayAPI.onCaptureStatusChanged !== "function") {
+      return;
+    }
+    return overlayAPI.onCaptureStatusChanged((status) => {
+      capturePausedRef.current = status === "paused";
+      setCapturePaused(status === "paused");
+      if (status !== "stopped") {
+        setCaptureStatus(status === "paused" ? "Capture paused." : "Capture running.");
+      }
+    });
+  }, [overlayAPI]);
+
+  const toggleCapturePause = useCallback(async () => {
+    if (!overlayAPI || typeof overlayAPI.pauseCapture !== "function") {
+      return;
+    }
+    try {
+      await (capturePausedRef.current
+        ? overlayAPI.resumeCapture()
+        : overlayAPI.pauseCapture(pauseReason));
+    } catch (error: unknown) {
+      setCaptureError(error instanceof Error ? error.message : "Could not change capture state.");
+    }
+  }, [overlayAPI, pauseReason]);
+
+  // Paused time is cut out of rate math by sliding each trackRate baseline
+  // forward over the gap, the same way idle stretches are skipped.
+  useEffect(() => {
+    if (!overlayAPI || typeof overlayAPI.onCapturePauseEnded !== "function") {
+      return;
+    }
+    return overlayAPI.onCapturePauseEnded((interval) => {
+      const pausedMs = interval.endedAt - interval.startedAt;
+      handleAddEventEntry({
+        id: buildEntryId(),
+        eventType: "pause",
+        timestamp: interval.startedAt,
+        note: `Paused (${interval.reason}) for ${Math.max(1, Math.round(pausedMs / 60000))} min.`
+      });
+      const current = rulesStoreRef.current;
+      const shifted = current.rules.map((rule) => {
+        if (rule.action.type !== "trackRate" || rule.state?.lastAt === undefined) {
+          return rule;
+        }
+        return {
+          ...rule,
+          state: {
+            ...rule.state,
+            lastAt: rule.state.lastAt + pausedMs,
+            samples: rule.state.samples?.map((sample) => ({
+              ...sample,
+              at: sample.at + pausedMs
+            }))
+          }
+        };
+      });
+      const next = { ...current, rules: shifted };
+      rulesStoreRef.current = next;
+      setRulesStore(next);
+      persistRules(next).catch(() => undefined);
+    });
+  }, [handleAddEventEntry, overlayAPI, persistRules]);
+
   useEffect(() => {
     if (!settings?.captureEnabled) {
+      capturePausedRef.current = false;
+      setCapturePaused(false);
       setCaptureStatus("Capture off.");
       setCaptureError(null);
       return;
@@ -1267,13 +1718,12 @@ const App = () => {
     } else {
       captureOnce(target).catch(() => undefined);
     }
-    const timer = setInterval(() => {
+    if (typeof overlayAPI.onCaptureTick !== "function") {
+      return;
+    }
+    return overlayAPI.onCaptureTick(() => {
       captureOnce(target).catch(() => undefined);
-    }, CAPTURE_INTERVAL_MS);
-
-    return () => {
-      clearInterval(timer);
-    };
+    });
   }, [
     captureOnce,
     overlayAPI,
@@ -1731,6 +2181,48 @@ const App = () => {
               ))}
             </select>
           </div>
+          <button type="button" onClick={handleDoNotDisturbToggle}>
+            {settings?.doNotDisturb.enabled ? "DND on" : "DND off"}
+          </button>
+          <div className="control-group">
+            <span className="label">Numbers</span>
+            <select value={settings?.numberLocale ?? "auto"} onChange={handleNumberLocaleChange}>
+              <option value="auto">Auto</option>
+              <option value="dot-decimal">1,234.5</option>
+              <option value="comma-decimal">1.234,5</option>
+            </select>
+          </div>
+          <div className="control-group">
+            <span className="label">Language</span>
+            <select value={settings?.language ?? "auto"} onChange={handleLanguageChange}>
+              <option value="auto">Auto</option>
+              <option value="en">English</option>
+              <option value="es">Español</option>
+              <option value="de">Deutsch</option>
+            </select>
+          </div>
+          <div className="control-group">
+            <span className="label">Spectate</span>
+            <input
+              type="text"
+              placeholder="http://192.168.1.10:4319"
+              value={settings?.spectator.url ?? ""}
+              onChange={(event) => updateSpectatorSettings({ url: event.target.value })}
+            />
+            <input
+              type="password"
+              placeholder="Primary's API token"
+              value={settings?.spectator.token ?? ""}
+              onChange={(event) => updateSpectatorSettings({ token: event.target.value })}
+            />
+            <button
+              type="button"
+              onClick={handleStartSpectating}
+              disabled={!settings?.spectator.url.trim()}
+            >
+              Start
+            </button>
+          </div>
           <div className="control-group">
             <span className="label">Capture</span>
             <button
@@ -1782,8 +2274,9 @@ const App = () => {
         <section className="widget-canvas">
           {activePlan && (
             <PlanRenderer
-              plan={activePlan}
+              plan={visiblePlan}
               eventLog={eventLog}
+              now={overlayState?.capturedAt ?? Date.now()}
               onAddEventEntry={handleAddManualEventEntry}
               onUpdate={handleWidgetUpdate}
             />
@@ -2154,7 +2647,29 @@ const App = () => {
                   {inspectorTab === "capture" && (
                     <div className="capture-panel">
                       <h3>Capture OCR</h3>
-                      <p className="capture-status">{captureStatus}</p>
+                      <p className="capture-status">
+                        {captureStatus}{" "}
+                        {settings?.captureEnabled && !capturePaused && (
+                          <select
+                            value={pauseReason}
+                            onChange={(event) => setPauseReason(event.target.value)}
+                          >
+                            {PAUSE_REASONS.map((reason) => (
+                              <option key={reason} value={reason}>
+                                {reason}
+                              </option>
+                            ))}
+                          </select>
+                        )}
+                        {settings?.captureEnabled && (
+                          <button
+                            type="button"
+                            onClick={() => toggleCapturePause().catch(() => undefined)}
+                          >
+                            {capturePaused ? "Resume" : "Pause"}
+                          </button>
+                        )}
+                      </p>
                       {captureError && <p className="capture-error">{captureError}</p>}
                       <p className="capture-meta">
                         {lastCaptureAt
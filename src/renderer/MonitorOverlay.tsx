@@ -0,0 +1,120 @@
+import React, { useEffect, useState } from "react";
+import { EventLog, OverlayPlan, SpectatorSnapshot } from "../shared/ipc";
+import { widgetSpecToOverlayPlan } from "../state/planStore";
+import PlanRenderer from "./PlanRenderer";
+import { useOverlayState } from "./overlayState";
+
+const emptyEventLog: EventLog = { version: "1.0", entries: [] };
+
+type MonitorOverlayProps = {
+  spectator?: boolean;
+  showSpectatorBar?: boolean;
+};
+
+// Read-only mirror of the saved plan for per-monitor overlay windows. The
+// main overlay owns all writes; main pushes each saved plan and event log here.
+// In spectator mode the plan and log come from another instance instead.
+const MonitorOverlay = ({ spectator = false, showSpectatorBar = false }: MonitorOverlayProps) => {
+  const [plan, setPlan] = useState<OverlayPlan | null>(null);
+  const [eventLog, setEventLog] = useState<EventLog>(emptyEventLog);
+  const [connection, setConnection] = useState<SpectatorSnapshot | null>(null);
+  const overlayState = useOverlayState();
+
+  useEffect(() => {
+    const overlayAPI = window.overlayAPI;
+    if (!overlayAPI || spectator) {
+      return;
+    }
+    let cancelled = false;
+    Promise.allSettled([overlayAPI.loadPlan(), overlayAPI.loadEventLog()]).then(
+      ([planResult, logResult]) => {
+        if (cancelled) {
+          return;
+        }
+        if (planResult.status === "fulfilled" && planResult.value.plan) {
+          setPlan(widgetSpecToOverlayPlan(planResult.value.plan));
+        }
+        if (logResult.status === "fulfilled") {
+          setEventLog(logResult.value);
+        }
+      }
+    );
+    const unsubscribePlan = overlayAPI.onPlanChanged((spec) => {
+      setPlan(widgetSpecToOverlayPlan(spec));
+    });
+    const unsubscribeEventLog = overlayAPI.onEventLogChanged(setEventLog);
+    return () => {
+      cancelled = true;
+      unsubscribePlan();
+      unsubscribeEventLog();
+    };
+  }, [spectator]);
+
+  useEffect(() => {
+    const overlayAPI = window.overlayAPI;
+    if (!overlayAPI || !spectator || typeof overlayAPI.onSpectatorSnapshot !== "function") {
+      return;
+    }
+    let cancelled = false;
+    const applySnapshot = (snapshot: SpectatorSnapshot) => {
+      setConnection(snapshot);
+      if (snapshot.plan) {
+        setPlan(widgetSpecToOverlayPlan(snapshot.plan));
+      }
+      if (snapshot.eventLog) {
+        setEventLog(snapshot.eventLog);
+      }
+    };
+    overlayAPI
+      .getSpectatorSnapshot()
+      .then((snapshot) => !cancelled && applySnapshot(snapshot))
+      .catch(() => undefined);
+    const unsubscribe = overlayAPI.onSpectatorSnapshot(applySnapshot);
+    return () => {
+      cancelled = true;
+      unsubscribe();
+    };
+  }, [spectator]);
+
+  const leaveSpectatorMode = async () => {
+    const overlayAPI = window.overlayAPI;
+    if (!overlayAPI) {
+      return;
+    }
+    const settings = await overlayAPI.getSettings();
+    await overlayAPI.saveSettings({
+      ...settings,
+      spectator: { ...settings.spectator, enabled: false }
+    });
+  };
+
+  return (
+    <div className="monitor-overlay">
+      {spectator && showSpectatorBar ? (
+        <div className="spectator-bar">
+          <span>
+            {connection?.connected
+              ? "Spectating"
+              : connection?.error
+                ? `Disconnected: ${connection.error}`
+                : "Connecting..."}
+          </span>
+          <button type="button" onClick={() => leaveSpectatorMode().catch(() => undefined)}>
+            Leave
+          </button>
+        </div>
+      ) : null}
+      {plan ? (
+        <PlanRenderer
+          plan={plan}
+          eventLog={eventLog}
+          now={overlayState?.capturedAt ?? Date.now()}
+          onAddEventEntry={() => undefined}
+          onUpdate={() => undefined}
+        />
+      ) : null}
+    </div>
+  );
+};
+
+export default MonitorOverlay;
@@ -12,11 +12,12 @@ import ProjectionWidget from "./widgets/ProjectionWidget";
 type Props = {
   plan: OverlayPlan;
   eventLog: EventLog;
+  now: number;
   onAddEventEntry: (entry: EventLogEntry) => void;
   onUpdate: (widget: OverlayWidget) => void;
 };
 
-const PlanRenderer = ({ plan, eventLog, onAddEventEntry, onUpdate }: Props) => {
+const PlanRenderer = ({ plan, eventLog, now, onAddEventEntry, onUpdate }: Props) => {
   return (
     <div className="plan-renderer">
       {plan.widgets.map((widget) => {
@@ -29,7 +30,7 @@ const PlanRenderer = ({ plan, eventLog, onAddEventEntry, onUpdate }: Props) => {
             );
           case "timer":
             return (
-              <TimerWidget key={widget.id} widget={widget} onChange={onUpdate} />
+              <TimerWidget key={widget.id} widget={widget} now={now} onChange={onUpdate} />
             );
           case "checklist":
             return (
@@ -41,6 +42,7 @@ const PlanRenderer = ({ plan, eventLog, onAddEventEntry, onUpdate }: Props) => {
                 key={widget.id}
                 widget={widget}
                 eventLog={eventLog}
+                now={now}
                 onAddEventEntry={onAddEventEntry}
                 onUpdate={onUpdate}
               />
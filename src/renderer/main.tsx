@@ -1,10 +1,22 @@
 import React from "react";
 import { createRoot } from "react-dom/client";
 import App from "./App";
+import MonitorOverlay from "./MonitorOverlay";
 import "./styles.css";
 
+const params = new URLSearchParams(window.location.search);
+const monitorMode = params.has("monitor");
+const spectatorMode = params.has("spectator");
+
 const container = document.getElementById("root");
 if (container) {
+  document.body.classList.toggle("monitor-mode", monitorMode || spectatorMode);
   const root = createRoot(container);
-  root.render(<App />);
+  root.render(
+    monitorMode || spectatorMode ? (
+      <MonitorOverlay spectator={spectatorMode} showSpectatorBar={!monitorMode} />
+    ) : (
+      <App />
+    )
+  );
 }
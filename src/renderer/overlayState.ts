@@ -0,0 +1,41 @@
+import { useEffect, useState } from "react";
+import { OverlaySnapshot } from "../shared/ipc";
+
+// Overlay data is pushed by the main process at each section's configured
+// cadence: the first render comes from one snapshot, later renders from the
+// changed sections in each delta. Windows keep no polling intervals of their own.
+export const useOverlayState = (): OverlaySnapshot | null => {
+  const [state, setState] = useState<OverlaySnapshot | null>(null);
+
+  useEffect(() => {
+    const overlayAPI = window.overlayAPI;
+    if (!overlayAPI || typeof overlayAPI.subscribeOverlayState !== "function") {
+      return;
+    }
+    let cancelled = false;
+    const applySnapshot = (snapshot: OverlaySnapshot) => {
+      if (!cancelled) {
+        setState((prev) => (prev && prev.capturedAt > snapshot.capturedAt ? prev : snapshot));
+      }
+    };
+    overlayAPI.getOverlaySnapshot().then(applySnapshot).catch(() => undefined);
+    overlayAPI.subscribeOverlayState().then(applySnapshot).catch(() => undefined);
+    const unsubscribeDelta = overlayAPI.onOverlayStateDelta((delta) =>
+      setState((prev) =>
+        prev ? { ...prev, ...delta.changes, capturedAt: delta.capturedAt } : prev
+      )
+    );
+    // Visibility changes are also pushed as they happen, ahead of the next delta.
+    const unsubscribeVisibility = overlayAPI.onWidgetVisibilityChanged((widgetVisibility) =>
+      setState((prev) => (prev ? { ...prev, widgetVisibility } : prev))
+    );
+    return () => {
+      cancelled = true;
+      unsubscribeDelta();
+      unsubscribeVisibility();
+      overlayAPI.unsubscribeOverlayState().catch(() => undefined);
+    };
+  }, []);
+
+  return state;
+};
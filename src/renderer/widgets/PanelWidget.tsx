@@ -11,11 +11,12 @@ import ProjectionWidget from "./ProjectionWidget";
 type Props = {
   widget: PanelWidgetType;
   eventLog: EventLog;
+  now: number;
   onAddEventEntry: (entry: EventLogEntry) => void;
   onUpdate: (widget: OverlayWidget) => void;
 };
 
-const PanelWidget = ({ widget, eventLog, onAddEventEntry, onUpdate }: Props) => {
+const PanelWidget = ({ widget, eventLog, now, onAddEventEntry, onUpdate }: Props) => {
   const updateChild = (child: OverlayWidget) => {
     onUpdate({
       ...widget,
@@ -41,7 +42,7 @@ const PanelWidget = ({ widget, eventLog, onAddEventEntry, onUpdate }: Props) =>
               );
             case "timer":
               return (
-                <TimerWidget key={child.id} widget={child} onChange={updateChild} />
+                <TimerWidget key={child.id} widget={child} now={now} onChange={updateChild} />
               );
             case "checklist":
               return (
@@ -57,6 +58,7 @@ const PanelWidget = ({ widget, eventLog, onAddEventEntry, onUpdate }: Props) =>
                   key={child.id}
                   widget={child}
                   eventLog={eventLog}
+                  now={now}
                   onAddEventEntry={onAddEventEntry}
                   onUpdate={updateChild}
                 />
@@ -1,8 +1,9 @@
-import React, { useEffect } from "react";
+import React from "react";
 import { TimerWidget as TimerWidgetType } from "../../shared/ipc";
 
 type Props = {
   widget: TimerWidgetType;
+  now: number;
   onChange: (next: TimerWidgetType) => void;
 };
 
@@ -12,26 +13,40 @@ const formatTime = (seconds: number) => {
   return `${String(mins).padStart(2, "0")}:${String(secs).padStart(2, "0")}`;
 };
 
-const TimerWidget = ({ widget, onChange }: Props) => {
-  useEffect(() => {
-    if (!widget.running) {
-      return;
-    }
-    const timer = window.setInterval(() => {
-      onChange({ ...widget, seconds: widget.seconds + 1 });
-    }, 1000);
-    return () => window.clearInterval(timer);
-  }, [widget, onChange]);
+// The widget keeps no interval of its own: it re-renders whenever the backend
+// pushes overlay state, and `now` comes from that push.
+const elapsedSeconds = (widget: TimerWidgetType, now: number) =>
+  widget.running && widget.startedAt !== undefined
+    ? Math.max(0, Math.floor((now - widget.startedAt) / 1000))
+    : 0;
+
+const stopped = (widget: TimerWidgetType, seconds: number): TimerWidgetType => {
+  const next = { ...widget, seconds, running: false };
+  delete next.startedAt;
+  return next;
+};
+
+const TimerWidget = ({ widget, now, onChange }: Props) => {
+  const seconds = widget.seconds + elapsedSeconds(widget, now);
 
   return (
     <div className="widget">
       {widget.title && <h3>{widget.title}</h3>}
-      <div className="timer-display">{formatTime(widget.seconds)}</div>
+      <div className="timer-display">{formatTime(seconds)}</div>
       <div className="timer-controls">
-        <button type="button" onClick={() => onChange({ ...widget, running: !widget.running })}>
+        <button
+          type="button"
+          onClick={() =>
+            onChange(
+              widget.running
+                ? stopped(widget, seconds)
+                : { ...widget, running: true, startedAt: Date.now() }
+            )
+          }
+        >
           {widget.running ? "Stop" : "Start"}
         </button>
-        <button type="button" onClick={() => onChange({ ...widget, seconds: 0, running: false })}>
+        <button type="button" onClick={() => onChange(stopped(widget, 0))}>
           Reset
         </button>
       </div>
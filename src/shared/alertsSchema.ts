@@ -0,0 +1,86 @@
+import { z } from "zod";
+
+const comparisonSchema = z.enum(["lt", "lte", "gt", "gte"]);
+
+export const captureRegionSchema = z.object({
+  x: z.number(),
+  y: z.number(),
+  width: z.number().positive(),
+  height: z.number().positive()
+});
+
+export const alertConditionSchema = z.discriminatedUnion("metric", [
+  z.object({
+    metric: z.literal("counterValue"),
+    counterId: z.string().min(1),
+    op: comparisonSchema,
+    value: z.number()
+  }),
+  z.object({
+    metric: z.literal("eventRate"),
+    eventType: z.string().min(1),
+    windowMinutes: z.number().int().min(1),
+    op: comparisonSchema,
+    value: z.number()
+  }),
+  z.object({
+    metric: z.literal("secondsSinceEvent"),
+    eventType: z.string().min(1),
+    op: comparisonSchema,
+    value: z.number().nonnegative()
+  }),
+  z.object({
+    metric: z.literal("timerRemainingSeconds"),
+    timerId: z.string().min(1),
+    op: comparisonSchema,
+    value: z.number()
+  }),
+  z.object({
+    metric: z.literal("trackRate"),
+    ruleId: z.string().min(1),
+    op: comparisonSchema,
+    value: z.number()
+  }),
+  z.object({
+    metric: z.literal("trackRatePercentOfAverage"),
+    ruleId: z.string().min(1),
+    op: comparisonSchema,
+    value: z.number().nonnegative()
+  }),
+  z.object({
+    metric: z.literal("rateGained"),
+    ruleId: z.string().min(1),
+    scope: z.enum(["sitting", "today"]),
+    op: comparisonSchema,
+    value: z.number()
+  })
+]);
+
+export const alertActionSchema = z.discriminatedUnion("type", [
+  z.object({ type: z.literal("toast") }),
+  z.object({ type: z.literal("sound") }),
+  z.object({ type: z.literal("webhook"), url: z.string().url() }),
+  z.object({
+    type: z.literal("screenshot"),
+    monitorId: z.string().min(1),
+    region: captureRegionSchema.optional()
+  })
+]);
+
+export const alertRuleSchema = z.object({
+  id: z.string().min(1),
+  name: z.string().min(1),
+  enabled: z.boolean(),
+  critical: z.boolean().optional(),
+  cooldownSeconds: z.number().int().min(0).optional(),
+  sustainSeconds: z.number().int().min(0).optional(),
+  condition: alertConditionSchema,
+  actions: z.array(alertActionSchema).min(1)
+});
+
+export const alertRulesStoreSchema = z.object({
+  version: z.literal("1.0"),
+  rules: z.array(alertRuleSchema)
+});
+
+export type AlertRulesStoreSchema = z.infer<typeof alertRulesStoreSchema>;
@@ -0,0 +1,38 @@
+import { z } from "zod";
+
+export const counterRecordSchema = z
+  .object({
+    id: z.string().min(1),
+    label: z.string().min(1),
+    value: z.number(),
+    createdAt: z.number().nonnegative(),
+    target: z.number().positive().optional(),
+    step: z.number().positive().optional(),
+    min: z.number().optional(),
+    max: z.number().optional(),
+    wrap: z.boolean().optional()
+  })
+  .refine(
+    (counter) =>
+      counter.min === undefined || counter.max === undefined || counter.min <= counter.max,
+    { message: "Counter min must not exceed max", path: ["min"] }
+  );
+
+export const countersStoreSchema = z.object({
+  version: z.literal("1.0"),
+  counters: z.array(counterRecordSchema)
+});
+
+export const counterEventSchema = z.object({
+  counterId: z.string().min(1),
+  value: z.number(),
+  delta: z.number(),
+  timestamp: z.number().nonnegative()
+});
+
+export const counterHistoryStoreSchema = z.object({
+  version: z.literal("1.0"),
+  events: z.array(counterEventSchema)
+});
+
+export type CountersStoreSchema = z.infer<typeof countersStoreSchema>;
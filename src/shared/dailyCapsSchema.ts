@@ -0,0 +1,21 @@
+import { z } from "zod";
+
+export const dailyCapSourceSchema = z.discriminatedUnion("type", [
+  z.object({ type: z.literal("rateRule"), ruleId: z.string().min(1) }),
+  z.object({ type: z.literal("eventCount"), eventType: z.string().min(1) })
+]);
+
+export const dailyCapSchema = z.object({
+  id: z.string().min(1),
+  label: z.string().min(1),
+  source: dailyCapSourceSchema,
+  limit: z.number().positive(),
+  warnAtPercent: z.number().min(1).max(100)
+});
+
+export const dailyCapsStoreSchema = z.object({
+  version: z.literal("1.0"),
+  caps: z.array(dailyCapSchema)
+});
+
+export type DailyCapsStoreSchema = z.infer<typeof dailyCapsStoreSchema>;
@@ -0,0 +1,71 @@
+import { ExpTable, ExpTableLevel } from "./ipc";
+
+const sortLevels = (levels: ExpTableLevel[]) => {
+  const byLevel = new Map<number, number>();
+  levels.forEach((entry) => byLevel.set(entry.level, entry.expToNext));
+  return Array.from(byLevel.entries())
+    .sort(([a], [b]) => a - b)
+    .map(([level, expToNext]) => ({ level, expToNext }));
+};
+
+const parseJsonLevels = (data: unknown): ExpTableLevel[] => {
+  if (!Array.isArray(data)) {
+    throw new Error("Exp table JSON must be an array.");
+  }
+  return data.map((item, index) => {
+    if (typeof item === "number") {
+      return { level: index + 1, expToNext: item };
+    }
+    const record = item as { level?: unknown; exp?: unknown; expToNext?: unknown };
+    const level = Number(record.level);
+    const expToNext = Number(record.expToNext ?? record.exp);
+    if (!Number.isInteger(level) || !Number.isFinite(expToNext)) {
+      throw new Error(`Exp table entry ${index + 1} needs numeric level and exp.`);
+    }
+    return { level, expToNext };
+  });
+};
+
+// CSV/TSV rows are "level,exp"; headers and blank lines are skipped.
+const parseDelimitedLevels = (content: string): ExpTableLevel[] =>
+  content
+    .split(/\r?\n/)
+    .map((line) => line.split(/[,;\t]/).map((cell) => cell.trim().replace(/[_\s]/g, "")))
+    .filter((cells) => cells.length >= 2 && /^\d+$/.test(cells[0]) && /^\d+(\.\d+)?$/.test(cells[1]))
+    .map((cells) => ({ level: Number(cells[0]), expToNext: Number(cells[1]) }));
+
+export const parseExpTableLevels = (content: string): ExpTableLevel[] => {
+  const trimmed = content.trim();
+  const levels = sortLevels(
+    trimmed.startsWith("[") ? parseJsonLevels(JSON.parse(trimmed)) : parseDelimitedLevels(trimmed)
+  ).filter((entry) => entry.level >= 1 && entry.expToNext > 0);
+  if (levels.length === 0) {
+    throw new Error("No exp table rows found. Expected JSON or level,exp lines.");
+  }
+  return levels;
+};
+
+// Total exp needed to reach the start of `level`, or null when the table
+// does not cover every level below it.
+export const cumulativeExpAt = (table: ExpTable, level: number): number | null => {
+  let total = 0;
+  for (let current = 1; current < level; current += 1) {
+    const entry = table.levels.find((candidate) => candidate.level === current);
+    if (!entry) {
+      return null;
+    }
+    total += entry.expToNext;
+  }
+  return total;
+};
+
+// Converts an in-level percentage into absolute exp so rates stay comparable
+// across level-ups.
+export const toAbsoluteExp = (table: ExpTable, level: number, percent: number): number | null => {
+  const base = cumulativeExpAt(table, level);
+  const entry = table.levels.find((candidate) => candidate.level === level);
+  if (base === null || !entry) {
+    return null;
+  }
+  return base + (entry.expToNext * percent) / 100;
+};
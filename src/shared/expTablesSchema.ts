@@ -0,0 +1,28 @@
+import { z } from "zod";
+
+export const expTableLevelSchema = z.object({
+  level: z.number().int().min(1),
+  expToNext: z.number().positive()
+});
+
+export const expTableSchema = z.object({
+  id: z.string().min(1),
+  name: z.string().min(1),
+  importedAt: z.number().nonnegative(),
+  levels: z.array(expTableLevelSchema).min(1)
+});
+
+export const expTablesStoreSchema = z
+  .object({
+    version: z.literal("1.0"),
+    activeTableId: z.string().nullable(),
+    tables: z.array(expTableSchema)
+  })
+  .refine(
+    (store) =>
+      store.activeTableId === null ||
+      store.tables.some((table) => table.id === store.activeTableId),
+    { message: "Active exp table must exist" }
+  );
+
+export type ExpTablesStoreSchema = z.infer<typeof expTablesStoreSchema>;
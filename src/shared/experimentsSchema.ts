@@ -0,0 +1,27 @@
+import { z } from "zod";
+
+export const experimentMarkerSchema = z.object({
+  id: z.string().min(1),
+  label: z.string().min(1),
+  markedAt: z.number().nonnegative(),
+  note: z.string().optional()
+});
+
+export const experimentsStoreSchema = z
+  .object({
+    version: z.literal("1.0"),
+    markers: z.array(experimentMarkerSchema)
+  })
+  .refine(
+    (store) =>
+      new Set(store.markers.map((marker) => marker.label.toLowerCase())).size ===
+      store.markers.length,
+    { message: "Experiment labels must be unique" }
+  );
+
+export const experimentCompareOptionsSchema = z.object({
+  eventType: z.string().min(1),
+  windowMinutes: z.number().int().min(1).max(7 * 24 * 60)
+});
+
+export type ExperimentsStoreSchema = z.infer<typeof experimentsStoreSchema>;
@@ -0,0 +1,68 @@
+import { RuleState } from "./ipc";
+
+// A gap this long between samples, or the rule going idle, starts a new sitting.
+export const SITTING_GAP_MS = 30 * 60 * 1000;
+
+export const localDayKey = (timestamp: number) => {
+  const date = new Date(timestamp);
+  const month = String(date.getMonth() + 1).padStart(2, "0");
+  const day = String(date.getDate()).padStart(2, "0");
+  return `${date.getFullYear()}-${month}-${day}`;
+};
+
+export type GainTotals = Pick<
+  RuleState,
+  "sittingGained" | "sittingStartedAt" | "dayGained" | "gainDay"
+>;
+
+export const accumulateGain = (
+  state: RuleState | undefined,
+  gained: number,
+  at: number,
+  wasIdle: boolean
+): GainTotals => {
+  const gainDay = localDayKey(at);
+  const continuesSitting =
+    state?.lastAt !== undefined && at - state.lastAt < SITTING_GAP_MS && !wasIdle;
+  const credited = Math.max(0, gained);
+  return {
+    sittingGained: (continuesSitting ? state?.sittingGained ?? 0 : 0) + credited,
+    sittingStartedAt: continuesSitting ? state?.sittingStartedAt ?? at : at,
+    dayGained: (state?.gainDay === gainDay ? state.dayGained ?? 0 : 0) + credited,
+    gainDay
+  };
+};
+
+// Totals read back as zero once their sitting or day has lapsed, so a stale
+// rule state cannot keep an alarm satisfied.
+export const readGainTotal = (
+  state: RuleState | undefined,
+  scope: "sitting" | "today",
+  now: number
+): number => {
+  if (!state) {
+    return 0;
+  }
+  if (scope === "today") {
+    return state.gainDay === localDayKey(now) ? state.dayGained ?? 0 : 0;
+  }
+  const lapsed = state.lastAt === undefined || now - state.lastAt >= SITTING_GAP_MS;
+  return lapsed ? 0 : state.sittingGained ?? 0;
+};
+
+const MIN_AVERAGE_MS = 60 * 1000;
+
+// Average per-hour gain over the current sitting, or null while the sitting
+// is too short (or has lapsed) for an average to mean anything.
+export const readSittingAverage = (state: RuleState | undefined, now: number): number | null => {
+  const startedAt = state?.sittingStartedAt;
+  const lastAt = state?.lastAt;
+  if (startedAt === undefined || lastAt === undefined || now - lastAt >= SITTING_GAP_MS) {
+    return null;
+  }
+  const durationMs = lastAt - startedAt;
+  if (durationMs < MIN_AVERAGE_MS) {
+    return null;
+  }
+  return (state?.sittingGained ?? 0) / (durationMs / 3600000);
+};
@@ -0,0 +1,23 @@
+import { z } from "zod";
+
+export const hotkeyBindingSchema = z.object({
+  id: z.string().min(1),
+  accelerator: z.string().min(1),
+  actionId: z.string().min(1),
+  args: z.record(z.unknown()).optional(),
+  enabled: z.boolean()
+});
+
+export const hotkeysStoreSchema = z
+  .object({
+    version: z.literal("1.0"),
+    bindings: z.array(hotkeyBindingSchema)
+  })
+  .refine(
+    (store) =>
+      new Set(store.bindings.map((binding) => binding.accelerator.toLowerCase())).size ===
+      store.bindings.length,
+    { message: "Each accelerator can only be bound once" }
+  );
+
+export type HotkeysStoreSchema = z.infer<typeof hotkeysStoreSchema>;
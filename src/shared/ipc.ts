@@ -9,16 +9,234 @@ export type OverlaySettings = {
   captureSourceType: CaptureSourceType | null;
   captureSourceId: string | null;
   captureRoi: CaptureRoi | null;
+  captureWatchdog: CaptureWatchdogSettings;
   uiMode: "gameplay" | "compose" | "inspect";
+  numberLocale: NumberLocale;
+  language: UiLanguage;
+  serverClock: ServerClockSettings;
+  pingMonitor: PingMonitorSettings;
+  httpApi: HttpApiSettings;
+  logTail: LogTailSettings;
+  doNotDisturb: DoNotDisturbSettings;
+  quietHours: QuietHoursSettings;
+  overlayRefresh: OverlayRefreshSettings;
+  retention: RetentionSettings;
+  spectator: SpectatorSettings;
   llm: LlmSettings;
 };
 
+export type CaptureStatus = "running" | "paused" | "stopped";
+
+export type CapturePauseInterval = {
+  reason: string;
+  startedAt: number;
+  endedAt: number;
+};
+
+export type CaptureWatchdogSettings = {
+  enabled: boolean;
+  stalledAfter: number;
+};
+
+export type RateReading = {
+  ruleId: string;
+  widgetId: string;
+  display: string | null;
+  value: number | null;
+  lastAt: number | null;
+  sittingGained: number;
+  todayGained: number;
+};
+
+// Spectator mode mirrors another instance's plan and event log through its
+// HTTP API instead of running a local capture loop.
+export type SpectatorSettings = {
+  enabled: boolean;
+  url: string;
+  token: string;
+  pollMs: number;
+};
+
+export type SpectatorSnapshot = {
+  connected: boolean;
+  lastSyncAt: number | null;
+  error: string | null;
+  plan: WidgetSpec | null;
+  eventLog: EventLog | null;
+};
+
+export type RetentionSettings = {
+  enabled: boolean;
+  rawDays: number;
+  rollupMinutes: number;
+};
+
+export type RetentionReport = {
+  ranAt: number;
+  eventLogEntriesCompacted: number;
+  counterEventsBefore: number;
+  counterEventsAfter: number;
+};
+
+export type CompatibilityFinding = {
+  processName: string;
+  label: string;
+  kind: "anticheat" | "overlay";
+};
+
+export type CompatibilitySuggestion = {
+  id: "disableCapture" | "disableWindowCapture" | "enableClickThrough";
+  description: string;
+};
+
+export type CompatibilityReport = {
+  checkedAt: number;
+  supported: boolean;
+  findings: CompatibilityFinding[];
+  suggestions: CompatibilitySuggestion[];
+};
+
+export type OverlayRefreshSettings = {
+  timersMs: number;
+  countersMs: number;
+  pingMs: number;
+  statusMs: number;
+  captureMs: number;
+};
+
+export type QuietHoursWindow = {
+  days: number[];
+  start: string;
+  end: string;
+};
+
+export type QuietHoursSettings = {
+  enabled: boolean;
+  windows: QuietHoursWindow[];
+};
+
+export type DoNotDisturbSettings = {
+  enabled: boolean;
+  until: number | null;
+};
+
+export type DoNotDisturbStatus = DoNotDisturbSettings & {
+  active: boolean;
+  quietHoursActive: boolean;
+};
+
+export type LogTailSettings = {
+  enabled: boolean;
+  path: string;
+};
+
+export type LogTailBatch = {
+  path: string;
+  lines: string[];
+  readAt: number;
+};
+
+export type LogTailPatternMatch = {
+  line: string;
+  match0: string;
+  groups: string[];
+};
+
+export type GameProcessStats = {
+  processName: string;
+  cpuPercent: number | null;
+  memoryBytes: number;
+};
+
+export type SystemStats = {
+  sampledAt: number;
+  cpuPercent: number | null;
+  memoryUsedBytes: number;
+  memoryTotalBytes: number;
+  appCpuPercent: number;
+  appMemoryBytes: number;
+  // Null unless a game window is the capture source (Windows only).
+  game: GameProcessStats | null;
+};
+
+export type HttpApiSettings = {
+  enabled: boolean;
+  host: string;
+  port: number;
+  token: string;
+  allowedOrigins: string[];
+};
+
+export type PingMonitorSettings = {
+  enabled: boolean;
+  host: string;
+  port: number;
+  intervalSeconds: number;
+};
+
+export type PingSample = {
+  at: number;
+  latencyMs: number | null;
+};
+
+// Persisted per profile so lag can be reviewed after a session; the target is
+// kept with each sample because it can change mid-session.
+export type PingHistorySample = PingSample & {
+  host: string;
+  port: number;
+};
+
+export type PingHistoryStore = {
+  version: "1.0";
+  samples: PingHistorySample[];
+};
+
+export type PingStats = {
+  host: string;
+  port: number;
+  currentMs: number | null;
+  averageMs: number | null;
+  jitterMs: number | null;
+  lossPercent: number;
+  samples: PingSample[];
+};
+
+export type ServerClockSettings = {
+  utcOffsetMinutes: number;
+  ntpEnabled: boolean;
+  ntpServer: string;
+};
+
+export type ServerTimeInfo = {
+  serverTime: number;
+  utcOffsetMinutes: number;
+  driftMs: number;
+  lastSyncAt: number | null;
+  roundTripMs: number | null;
+  syncError?: string;
+};
+
+export type NumberLocale = "auto" | "dot-decimal" | "comma-decimal";
+
+export type UiLanguage = "auto" | "en" | "es" | "de";
+
+export type LanguageCatalog = {
+  language: Exclude<UiLanguage, "auto">;
+  strings: Record<string, string>;
+};
+
 export type DisplayInfo = {
   id: number;
   label: string;
   bounds: { x: number; y: number; width: number; height: number };
 };
 
+export type OverlayWindowInfo = {
+  monitorId: number;
+  label: string;
+  bounds: { x: number; y: number; width: number; height: number };
+};
+
 export type OverlayPlan = {
   version: "1.0";
   widgets: OverlayWidget[];
@@ -45,6 +263,15 @@ export type PlanSnapshotPayload = {
 
 export type NotePayload = {
   text: string;
+  updatedAt?: number;
+  pinned?: boolean;
+};
+
+export type NoteEntry = MemoryEntryBase & { type: "note"; payload: NotePayload };
+
+export type NoteUpdate = {
+  text?: string;
+  tags?: string[];
 };
 
 export type MemoryEntryBase = {
@@ -70,6 +297,225 @@ export type MemoryStore = {
   entries: MemoryEntry[];
 };
 
+export type NoteSearchHit = {
+  entry: MemoryEntry & { type: "note" };
+  score: number;
+  snippet: string;
+};
+
+export type GlobalSearchKind = "note" | "timer" | "counter" | "widget" | "rule" | "setting";
+
+export type GlobalSearchResult = {
+  kind: GlobalSearchKind;
+  id: string;
+  title: string;
+  detail?: string;
+  score: number;
+};
+
+export type AlertComparison = "lt" | "lte" | "gt" | "gte";
+
+export type AlertCondition =
+  | { metric: "counterValue"; counterId: string; op: AlertComparison; value: number }
+  | {
+      metric: "eventRate";
+      eventType: string;
+      windowMinutes: number;
+      op: AlertComparison;
+      value: number;
+    }
+  | { metric: "secondsSinceEvent"; eventType: string; op: AlertComparison; value: number }
+  | { metric: "timerRemainingSeconds"; timerId: string; op: AlertComparison; value: number }
+  | { metric: "trackRate"; ruleId: string; op: AlertComparison; value: number }
+  | { metric: "trackRatePercentOfAverage"; ruleId: string; op: AlertComparison; value: number }
+  | {
+      metric: "rateGained";
+      ruleId: string;
+      scope: "sitting" | "today";
+      op: AlertComparison;
+      value: number;
+    };
+
+export type AlertAction =
+  | { type: "toast" }
+  | { type: "sound" }
+  | { type: "webhook"; url: string }
+  | { type: "screenshot"; monitorId: string; region?: CaptureRegion };
+
+export type AlertRule = {
+  id: string;
+  name: string;
+  enabled: boolean;
+  critical?: boolean;
+  cooldownSeconds?: number;
+  sustainSeconds?: number;
+  condition: AlertCondition;
+  actions: AlertAction[];
+};
+
+export type AlertRulesStore = {
+  version: "1.0";
+  rules: AlertRule[];
+};
+
+export type AlertEvent = {
+  ruleId: string;
+  name: string;
+  critical: boolean;
+  metricValue: number;
+  message: string;
+  firedAt: number;
+  screenshotPath?: string;
+};
+
+export type DailyCap = {
+  id: string;
+  label: string;
+  source: { type: "rateRule"; ruleId: string } | { type: "eventCount"; eventType: string };
+  limit: number;
+  warnAtPercent: number;
+};
+
+export type DailyCapsStore = {
+  version: "1.0";
+  caps: DailyCap[];
+};
+
+export type DailyCapStatus = {
+  capId: string;
+  label: string;
+  day: string;
+  used: number;
+  limit: number;
+  remaining: number;
+  percentUsed: number;
+  nearCap: boolean;
+};
+
+export type AlertNotification = {
+  event: AlertEvent;
+  sound: boolean;
+};
+
+export type WidgetVisibilityCondition =
+  | { when: "captureEnabled" }
+  // True while the overlay is in an edit mode (compose or inspect).
+  | { when: "interactive" }
+  | { when: "timerRemainingBelow"; timerId: string; seconds: number };
+
+export type WidgetVisibilityRule = {
+  match: "all" | "any";
+  conditions: WidgetVisibilityCondition[];
+};
+
+export type WidgetVisibilityState = Record<string, boolean>;
+
+export type OverlaySnapshot = {
+  capturedAt: number;
+  timers: TimerListResult;
+  counters: CounterStatus[];
+  widgetVisibility: WidgetVisibilityState;
+  doNotDisturb: DoNotDisturbStatus;
+  serverTime: ServerTimeInfo;
+  ping: PingStats;
+};
+
+export type OverlayStateDelta = {
+  capturedAt: number;
+  changes: Partial<OverlaySnapshot>;
+};
+
+export type HotkeyBinding = {
+  id: string;
+  accelerator: string;
+  actionId: string;
+  args?: Record<string, unknown>;
+  enabled: boolean;
+};
+
+export type HotkeysStore = {
+  version: "1.0";
+  bindings: HotkeyBinding[];
+};
+
+export type HotkeyStatus = HotkeyBinding & {
+  registered: boolean;
+  error?: string;
+};
+
+export type ProfileBackupResult = {
+  path: string;
+  createdAt: number;
+  files: string[];
+};
+
+export type ProfileImportSummary = {
+  name: string;
+  sourceUrl: string;
+  stagedAt: number;
+  widgetCount: number;
+  ruleCount: number;
+};
+
+export type ProfileImportResult = {
+  plan: WidgetSpec;
+  rules?: RulesStore;
+};
+
+export type ExperimentMarker = {
+  id: string;
+  label: string;
+  markedAt: number;
+  note?: string;
+};
+
+export type ExperimentsStore = {
+  version: "1.0";
+  markers: ExperimentMarker[];
+};
+
+export type ExperimentCompareOptions = {
+  eventType: string;
+  windowMinutes: number;
+};
+
+export type ExperimentWindowStats = {
+  start: number;
+  end: number;
+  count: number;
+  ratePerHour: number;
+};
+
+export type ExperimentComparison = {
+  label: string;
+  markedAt: number;
+  eventType: string;
+  windowMinutes: number;
+  before: ExperimentWindowStats;
+  after: ExperimentWindowStats;
+  changePercent: number | null;
+  zScore: number | null;
+  pValue: number | null;
+  significant: boolean;
+};
+
+export type ActionArgDef = {
+  key: string;
+  label: string;
+  type: "string" | "number" | "boolean";
+  required?: boolean;
+};
+
+export type ActionDescriptor = {
+  id: string;
+  title: string;
+  description: string;
+  category: string;
+  args: ActionArgDef[];
+};
+
+export type ActionResult = { ok: true; result?: unknown } | { ok: false; error: string };
+
 export type RuleAction =
   | {
       type: "setTextWidget";
@@ -89,18 +535,78 @@ export type RuleAction =
       unit?: string;
       precision?: number;
       minSeconds?: number;
+      rolloverAt?: number;
+      levelSource?: "g2";
+      rateMethod?: "delta" | "ewma" | "regression";
+      ewmaAlpha?: number;
+      regressionSamples?: number;
+      idleAfterTicks?: number;
+      smoothing?: ValueSmoothing;
+      screenshotOnLevelUp?: { monitorId: string; region?: CaptureRegion };
     };
 
+export type ExpTableLevel = {
+  level: number;
+  expToNext: number;
+};
+
+export type ExpTable = {
+  id: string;
+  name: string;
+  importedAt: number;
+  levels: ExpTableLevel[];
+};
+
+export type ExpTablesStore = {
+  version: "1.0";
+  activeTableId: string | null;
+  tables: ExpTable[];
+};
+
+export type ExpTableSummary = {
+  id: string;
+  name: string;
+  importedAt: number;
+  levelCount: number;
+  maxLevel: number;
+  active: boolean;
+};
+
+export type ValueSmoothing = {
+  rejectRegressions?: boolean;
+  maxJumpRatio?: number;
+  confirmTicks?: number;
+};
+
+export type RateSample = {
+  at: number;
+  total: number;
+};
+
+export type RuleState = {
+  lastValue?: number;
+  lastAt?: number;
+  pendingValue?: number;
+  pendingCount?: number;
+  smoothedRate?: number;
+  lastRate?: number;
+  samples?: RateSample[];
+  idleTicks?: number;
+  sittingGained?: number;
+  sittingStartedAt?: number;
+  dayGained?: number;
+  gainDay?: string;
+  // Level text the active exp table could not resolve, reported once.
+  missingLevel?: string;
+};
+
 export type Rule = {
   id: string;
   enabled: boolean;
   mode: "includes" | "regex";
   pattern: string;
   action: RuleAction;
-  state?: {
-    lastValue?: number;
-    lastAt?: number;
-  };
+  state?: RuleState;
 };
 
 export type RulesStore = {
@@ -108,6 +614,115 @@ export type RulesStore = {
   rules: Rule[];
 };
 
+export type TimerRecord = {
+  id: string;
+  label: string;
+  endsAt: number;
+  createdAt: number;
+  category?: string;
+  color?: string;
+  repeatSeconds?: number;
+};
+
+export type TimerCreateInput = {
+  label: string;
+  durationSeconds: number;
+  category?: string;
+  color?: string;
+  repeatSeconds?: number;
+};
+
+export type TimerUpdate = Partial<
+  Pick<TimerRecord, "label" | "endsAt" | "category" | "color" | "repeatSeconds">
+>;
+
+export type TimerExpiredEvent = {
+  timerId: string;
+  label: string;
+  endsAt: number;
+  firedAt: number;
+  nextEndsAt: number | null;
+  message: string;
+};
+
+export type TimerSortOrder = "soonest" | "created" | "label";
+
+export type TimerListOptions = {
+  sort: TimerSortOrder;
+  groupByCategory: boolean;
+};
+
+export type TimersStore = {
+  version: "1.0";
+  timers: TimerRecord[];
+  listOptions: TimerListOptions;
+};
+
+export type TimerGroup = {
+  category: string | null;
+  timers: TimerRecord[];
+};
+
+export type TimerListResult = {
+  options: TimerListOptions;
+  timers: TimerRecord[];
+  groups: TimerGroup[];
+};
+
+export type CounterRecord = {
+  id: string;
+  label: string;
+  value: number;
+  createdAt: number;
+  target?: number;
+  step?: number;
+  min?: number;
+  max?: number;
+  wrap?: boolean;
+};
+
+export type CounterCreateInput = Omit<CounterRecord, "id" | "createdAt" | "value"> & {
+  value?: number;
+};
+
+export type CountersStore = {
+  version: "1.0";
+  counters: CounterRecord[];
+};
+
+export type CounterEvent = {
+  counterId: string;
+  value: number;
+  delta: number;
+  timestamp: number;
+};
+
+export type CsvExportResult = {
+  path: string;
+  rowCount: number;
+};
+
+export type WidgetExportFormat = "csv" | "json";
+
+export type CounterHistoryStore = {
+  version: "1.0";
+  events: CounterEvent[];
+};
+
+export type CounterStatus = CounterRecord & {
+  progressPercent: number | null;
+  completed: boolean;
+};
+
+export type CounterGoalEvent = {
+  counterId: string;
+  label: string;
+  value: number;
+  target: number;
+  reachedAt: number;
+  message: string;
+};
+
 export type LlmProvider =
   | "openai"
   | "groq"
@@ -202,6 +817,22 @@ export type CaptureSnapshotResult = {
   dataUrl: string;
 };
 
+export type CaptureRegion = {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+};
+
+export type CaptureImageResult = {
+  sourceId: string;
+  sourceName: string;
+  capturedAt: number;
+  width: number;
+  height: number;
+  pngBase64: string;
+};
+
 export type PlanLoadResult = {
   plan: WidgetSpec | null;
   warning?: string;
@@ -237,6 +868,8 @@ export type TimerWidget = BaseWidget & {
   type: "timer";
   seconds: number;
   running: boolean;
+  // Set while running; `seconds` holds the time banked before this run.
+  startedAt?: number;
 };
 
 export type ChecklistItem = {
@@ -296,13 +929,129 @@ export type OverlayAPI = {
   saveMemory: (store: MemoryStore) => Promise<void>;
   addMemoryEntry: (entry: MemoryEntry) => Promise<MemoryStore>;
   deleteMemoryEntry: (entryId: string) => Promise<MemoryStore>;
+  createNote: (text: string, tags?: string[]) => Promise<NoteEntry>;
+  listNotes: () => Promise<NoteEntry[]>;
+  updateNote: (noteId: string, update: NoteUpdate) => Promise<NoteEntry>;
+  deleteNote: (noteId: string) => Promise<void>;
+  pinNote: (noteId: string, pinned: boolean) => Promise<NoteEntry>;
+  searchNotes: (query: string) => Promise<NoteSearchHit[]>;
+  globalSearch: (query: string) => Promise<GlobalSearchResult[]>;
+  captureMonitor: (monitorId: string) => Promise<CaptureImageResult>;
+  captureRegion: (monitorId: string, region: CaptureRegion) => Promise<CaptureImageResult>;
+  captureEventScreenshot: (
+    monitorId: string,
+    label: string,
+    region?: CaptureRegion
+  ) => Promise<string>;
+  getOverlaySnapshot: () => Promise<OverlaySnapshot>;
+  subscribeOverlayState: () => Promise<OverlaySnapshot>;
+  unsubscribeOverlayState: () => Promise<void>;
+  getWidgetVisibility: () => Promise<WidgetVisibilityState>;
+  getServerTime: () => Promise<ServerTimeInfo>;
+  syncServerClock: () => Promise<ServerTimeInfo>;
+  getPingStats: () => Promise<PingStats>;
+  getPingHistory: (from?: number, to?: number) => Promise<PingHistorySample[]>;
+  getSystemStats: () => Promise<SystemStats>;
+  setLogTailPath: (path: string) => Promise<OverlaySettings>;
+  getDoNotDisturb: () => Promise<DoNotDisturbStatus>;
+  setDoNotDisturb: (enabled: boolean, until?: number | null) => Promise<DoNotDisturbStatus>;
+  testLogTailPattern: (pattern: string) => Promise<LogTailPatternMatch[]>;
+  loadAlertRules: () => Promise<AlertRulesStore>;
+  saveAlertRules: (store: AlertRulesStore) => Promise<void>;
+  listActions: () => Promise<ActionDescriptor[]>;
+  getOverlayClickThrough: (windowLabel?: string) => Promise<boolean>;
+  setOverlayClickThrough: (windowLabel: string, enabled: boolean) => Promise<boolean>;
+  createOverlayWindow: (monitorId: number) => Promise<OverlayWindowInfo>;
+  closeOverlayWindow: (monitorId: number) => Promise<void>;
+  listOverlayWindows: () => Promise<OverlayWindowInfo[]>;
+  listHotkeys: () => Promise<HotkeyStatus[]>;
+  runRetentionNow: () => Promise<RetentionReport>;
+  getSpectatorSnapshot: () => Promise<SpectatorSnapshot>;
+  onSpectatorSnapshot: (callback: (snapshot: SpectatorSnapshot) => void) => () => void;
+  getLanguageCatalog: () => Promise<LanguageCatalog>;
+  loadDailyCaps: () => Promise<DailyCapsStore>;
+  saveDailyCaps: (store: DailyCapsStore) => Promise<void>;
+  getDailyCapStatuses: () => Promise<DailyCapStatus[]>;
+  getCompatibilityReport: (refresh?: boolean) => Promise<CompatibilityReport>;
+  backupProfile: (destPath: string) => Promise<ProfileBackupResult>;
+  restoreProfile: (srcPath: string) => Promise<ProfileBackupResult>;
+  getStagedProfileImport: () => Promise<ProfileImportSummary | null>;
+  applyStagedProfileImport: () => Promise<ProfileImportResult>;
+  discardStagedProfileImport: () => Promise<void>;
+  importExpTable: (name: string, content: string) => Promise<ExpTableSummary>;
+  listExpTables: () => Promise<ExpTableSummary[]>;
+  setActiveExpTable: (tableId: string | null) => Promise<ExpTableSummary[]>;
+  getActiveExpTable: () => Promise<ExpTable | null>;
+  listExperiments: () => Promise<ExperimentMarker[]>;
+  markExperiment: (label: string, note?: string) => Promise<ExperimentMarker>;
+  deleteExperiment: (label: string) => Promise<void>;
+  compareExperiment: (
+    label: string,
+    options: ExperimentCompareOptions
+  ) => Promise<ExperimentComparison>;
+  setHotkey: (binding: HotkeyBinding) => Promise<HotkeyStatus[]>;
+  deleteHotkey: (bindingId: string) => Promise<HotkeyStatus[]>;
+  executeAction: (actionId: string, args?: Record<string, unknown>) => Promise<ActionResult>;
   loadRules: () => Promise<RulesStore>;
   saveRules: (store: RulesStore) => Promise<void>;
+  loadTimers: () => Promise<TimersStore>;
+  saveTimers: (store: TimersStore) => Promise<void>;
+  listTimers: (options?: Partial<TimerListOptions>) => Promise<TimerListResult>;
+  setTimerListOptions: (options: TimerListOptions) => Promise<TimerListOptions>;
+  createTimer: (input: TimerCreateInput) => Promise<TimerRecord>;
+  updateTimer: (timerId: string, update: TimerUpdate) => Promise<TimerRecord>;
+  deleteTimer: (timerId: string) => Promise<void>;
+  extendTimer: (timerId: string, seconds: number) => Promise<TimerRecord>;
+  loadCounters: () => Promise<CountersStore>;
+  saveCounters: (store: CountersStore) => Promise<void>;
+  listCounters: () => Promise<CounterStatus[]>;
+  stepCounter: (counterId: string, direction: 1 | -1) => Promise<CounterStatus>;
+  createCounter: (input: CounterCreateInput) => Promise<CounterStatus>;
+  incrementCounter: (counterId: string, delta: number) => Promise<CounterStatus>;
+  applyCounterInput: (counterId: string, input: string) => Promise<CounterStatus>;
+  exportCounterHistoryCsv: (
+    path: string,
+    counterId?: string,
+    from?: number,
+    to?: number
+  ) => Promise<CsvExportResult>;
+  exportEventLogCsv: (path: string, from?: number, to?: number) => Promise<CsvExportResult>;
+  exportWidgetData: (
+    path: string,
+    widgetId: string,
+    format: WidgetExportFormat,
+    from?: number,
+    to?: number
+  ) => Promise<CsvExportResult>;
+  resetCounter: (counterId: string) => Promise<CounterStatus>;
+  deleteCounter: (counterId: string) => Promise<void>;
+  getCounterHistory: (counterId: string, from?: number, to?: number) => Promise<CounterEvent[]>;
   listCaptureSources: () => Promise<CaptureSource[]>;
   captureAndProcess: (target: CaptureTarget | null) => Promise<OcrResult | null>;
   captureSnapshot: (target: CaptureTarget) => Promise<CaptureSnapshotResult>;
   stopCapture: () => void;
+  getCaptureStatus: () => Promise<CaptureStatus>;
+  pauseCapture: (reason?: string) => Promise<CaptureStatus>;
+  resumeCapture: () => Promise<CaptureStatus>;
+  onCaptureStatusChanged: (callback: (status: CaptureStatus) => void) => () => void;
+  onCapturePauseEnded: (callback: (interval: CapturePauseInterval) => void) => () => void;
+  onCaptureTick: (callback: (at: number) => void) => () => void;
   onEscapeHatch: (callback: () => void) => () => void;
+  onBeforeQuit: (callback: () => Promise<void> | void) => () => void;
+  onTimerExpired: (callback: (event: TimerExpiredEvent) => void) => () => void;
+  onCounterGoalReached: (callback: (event: CounterGoalEvent) => void) => () => void;
+  onSettingsChanged: (callback: (settings: OverlaySettings) => void) => () => void;
+  onPlanChanged: (callback: (plan: WidgetSpec) => void) => () => void;
+  onEventLogChanged: (callback: (log: EventLog) => void) => () => void;
+  onProfileImportStaged: (callback: (summary: ProfileImportSummary) => void) => () => void;
+  onAlertFired: (callback: (notification: AlertNotification) => void) => () => void;
+  onLogTailLines: (callback: (batch: LogTailBatch) => void) => () => void;
+  onOverlayStateDelta: (callback: (delta: OverlayStateDelta) => void) => () => void;
+  onPingSample: (callback: (stats: PingStats) => void) => () => void;
+  onSystemStats: (callback: (stats: SystemStats) => void) => () => void;
+  onWidgetVisibilityChanged: (
+    callback: (state: WidgetVisibilityState) => void
+  ) => () => void;
 };
 
 declare global {
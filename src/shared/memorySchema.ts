@@ -19,7 +19,9 @@ const planSnapshotPayloadSchema = z.object({
 });
 
 const notePayloadSchema = z.object({
-  text: z.string().min(1)
+  text: z.string().min(1),
+  updatedAt: z.number().nonnegative().optional(),
+  pinned: z.boolean().optional()
 });
 
 const genericPayloadSchema = z.record(z.unknown());
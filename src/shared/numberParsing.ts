@@ -0,0 +1,79 @@
+import { NumberLocale } from "./ipc";
+
+const FULLWIDTH_OFFSET = 0xfee0;
+
+// OCR and game logs from non-English clients mix full-width digits,
+// locale-specific separators, and space-like grouping characters.
+const normalizeGlyphs = (raw: string) =>
+  raw
+    .replace(/[\uff10-\uff19]/g, (digit) =>
+      String.fromCharCode(digit.charCodeAt(0) - FULLWIDTH_OFFSET)
+    )
+    .replace(/[\uff0c\u066b]/g, ",")
+    .replace(/\uff0e/g, ".")
+    .replace(/[\u2212\uff0d]/g, "-")
+    .replace(/[\s\u00a0\u2009\u202f'\u2019]/g, "");
+
+const normalizeAuto = (cleaned: string) => {
+  const hasComma = cleaned.includes(",");
+  const hasDot = cleaned.includes(".");
+  if (hasComma && hasDot) {
+    const decimalIndex = Math.max(cleaned.lastIndexOf(","), cleaned.lastIndexOf("."));
+    const integerPart = cleaned.slice(0, decimalIndex).replace(/[.,]/g, "");
+    return `${integerPart}.${cleaned.slice(decimalIndex + 1)}`;
+  }
+  if (hasComma || hasDot) {
+    const parts = cleaned.split(hasComma ? "," : ".");
+    const last = parts[parts.length - 1] ?? "";
+    if (last.length === 3 && parts.length > 1) {
+      return parts.join("");
+    }
+    return `${parts.slice(0, -1).join("")}.${last}`;
+  }
+  return cleaned;
+};
+
+export const parseNumericValue = (raw: string, locale: NumberLocale = "auto"): number | null => {
+  const cleaned = normalizeGlyphs(raw).replace(/[^0-9,.-]/g, "");
+  if (!cleaned) {
+    return null;
+  }
+  let normalized: string;
+  if (locale === "dot-decimal") {
+    normalized = cleaned.replace(/,/g, "");
+  } else if (locale === "comma-decimal") {
+    normalized = cleaned.replace(/\./g, "").replace(/,/g, ".");
+  } else {
+    normalized = normalizeAuto(cleaned);
+  }
+  const value = Number(normalized);
+  return Number.isFinite(value) ? value : null;
+};
+
+const MAGNITUDE_SUFFIXES: Record<string, number> = {
+  k: 1e3,
+  m: 1e6,
+  b: 1e9
+};
+
+export type QuickEntry = { mode: "relative" | "absolute"; value: number };
+
+// Manual entry shorthand: "+0.42%", "-3", "+1.2m exp" adjust the current
+// value; a bare or "="-prefixed number replaces it. Trailing unit words are
+// ignored.
+export const parseQuickEntry = (raw: string, locale: NumberLocale = "auto"): QuickEntry | null => {
+  const match = /^\s*([+\-\u2212=]?)\s*([^a-z%]*?)\s*([kmb])?\s*%?\s*[a-z]*\s*$/i.exec(raw);
+  if (!match || !match[2]) {
+    return null;
+  }
+  const magnitude = match[3] ? MAGNITUDE_SUFFIXES[match[3].toLowerCase()] : 1;
+  const parsed = parseNumericValue(match[2], locale);
+  if (parsed === null) {
+    return null;
+  }
+  const sign = match[1] === "-" || match[1] === "\u2212" ? -1 : 1;
+  return {
+    mode: match[1] && match[1] !== "=" ? "relative" : "absolute",
+    value: sign * parsed * magnitude
+  };
+};
@@ -0,0 +1,13 @@
+import { z } from "zod";
+
+export const pingHistorySampleSchema = z.object({
+  at: z.number().nonnegative(),
+  latencyMs: z.number().nonnegative().nullable(),
+  host: z.string(),
+  port: z.number().int().min(1).max(65535)
+});
+
+export const pingHistoryStoreSchema = z.object({
+  version: z.literal("1.0"),
+  samples: z.array(pingHistorySampleSchema)
+});
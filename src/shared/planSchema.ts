@@ -32,7 +32,8 @@ export const counterWidgetSchema = baseWidget.extend({
 export const timerWidgetSchema = baseWidget.extend({
   type: z.literal("timer"),
   seconds: z.number().min(0),
-  running: z.boolean()
+  running: z.boolean(),
+  startedAt: z.number().nonnegative().optional()
 });
 
 export const checklistItemSchema = z.object({
@@ -0,0 +1,13 @@
+import { z } from "zod";
+import { rulesStoreSchema } from "./rulesSchema";
+
+// The plan is checked separately with validateWidgetSpec so import errors
+// read the same as any other plan load.
+export const profileBundleSchema = z.object({
+  version: z.literal("1.0"),
+  name: z.string().min(1).max(120),
+  plan: z.unknown(),
+  rules: rulesStoreSchema.optional()
+});
+
+export type ProfileBundleSchema = z.infer<typeof profileBundleSchema>;
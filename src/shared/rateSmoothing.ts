@@ -0,0 +1,61 @@
+import { RateSample, RuleState } from "./ipc";
+
+const DEFAULT_EWMA_ALPHA = 0.3;
+const DEFAULT_REGRESSION_SAMPLES = 10;
+const MS_PER_HOUR = 3600000;
+
+export type RateMethodOptions = {
+  rateMethod?: "delta" | "ewma" | "regression";
+  ewmaAlpha?: number;
+  regressionSamples?: number;
+};
+
+export type SmoothedRate = {
+  rate: number;
+  smoothedRate?: number;
+  samples?: RateSample[];
+};
+
+// Least-squares slope of cumulative gain over time, in units per hour.
+const regressionSlope = (samples: RateSample[]) => {
+  const origin = samples[0].at;
+  const xs = samples.map((sample) => (sample.at - origin) / MS_PER_HOUR);
+  const meanX = xs.reduce((sum, x) => sum + x, 0) / xs.length;
+  const meanY = samples.reduce((sum, sample) => sum + sample.total, 0) / samples.length;
+  let numerator = 0;
+  let denominator = 0;
+  xs.forEach((x, index) => {
+    numerator += (x - meanX) * (samples[index].total - meanY);
+    denominator += (x - meanX) ** 2;
+  });
+  return denominator > 0 ? numerator / denominator : null;
+};
+
+// "delta" reports the latest interval as-is; "ewma" blends it into the
+// previous rate; "regression" fits a line through the last few cumulative
+// gains so a single noisy interval cannot swing the display.
+export const computeSmoothedRate = (
+  options: RateMethodOptions,
+  state: RuleState | undefined,
+  interval: { from: number; to: number; gained: number }
+): SmoothedRate => {
+  const rawRate = interval.gained / ((interval.to - interval.from) / MS_PER_HOUR);
+  switch (options.rateMethod ?? "delta") {
+    case "ewma": {
+      const alpha = options.ewmaAlpha ?? DEFAULT_EWMA_ALPHA;
+      const previous = state?.smoothedRate;
+      const rate = previous === undefined ? rawRate : alpha * rawRate + (1 - alpha) * previous;
+      return { rate, smoothedRate: rate };
+    }
+    case "regression": {
+      const prior = state?.samples?.length ? state.samples : [{ at: interval.from, total: 0 }];
+      const last = prior[prior.length - 1];
+      const samples = [...prior, { at: interval.to, total: last.total + interval.gained }].slice(
+        -(options.regressionSamples ?? DEFAULT_REGRESSION_SAMPLES)
+      );
+      return { rate: regressionSlope(samples) ?? rawRate, samples };
+    }
+    default:
+      return { rate: rawRate };
+  }
+};
@@ -1,4 +1,5 @@
 import { z } from "zod";
+import { captureRegionSchema } from "./alertsSchema";
 
 export const ruleActionSchema = z.discriminatedUnion("type", [
   z.object({
@@ -18,7 +19,26 @@ export const ruleActionSchema = z.discriminatedUnion("type", [
     valueSource: z.enum(["match0", "g1"]).optional(),
     unit: z.string().optional(),
     precision: z.number().int().min(0).max(6).optional(),
-    minSeconds: z.number().int().min(1).optional()
+    minSeconds: z.number().int().min(1).optional(),
+    rolloverAt: z.number().positive().optional(),
+    levelSource: z.literal("g2").optional(),
+    rateMethod: z.enum(["delta", "ewma", "regression"]).optional(),
+    ewmaAlpha: z.number().gt(0).max(1).optional(),
+    regressionSamples: z.number().int().min(2).max(60).optional(),
+    idleAfterTicks: z.number().int().min(1).optional(),
+    smoothing: z
+      .object({
+        rejectRegressions: z.boolean().optional(),
+        maxJumpRatio: z.number().gt(1).optional(),
+        confirmTicks: z.number().int().min(1).max(20).optional()
+      })
+      .optional(),
+    screenshotOnLevelUp: z
+      .object({
+        monitorId: z.string().min(1),
+        region: captureRegionSchema.optional()
+      })
+      .optional()
   })
 ]);
 
@@ -31,7 +51,20 @@ export const ruleSchema = z.object({
   state: z
     .object({
       lastValue: z.number().optional(),
-      lastAt: z.number().nonnegative().optional()
+      lastAt: z.number().nonnegative().optional(),
+      pendingValue: z.number().optional(),
+      pendingCount: z.number().int().min(0).optional(),
+      smoothedRate: z.number().optional(),
+      lastRate: z.number().optional(),
+      samples: z
+        .array(z.object({ at: z.number().nonnegative(), total: z.number() }))
+        .optional(),
+      idleTicks: z.number().int().min(0).optional(),
+      sittingGained: z.number().nonnegative().optional(),
+      sittingStartedAt: z.number().nonnegative().optional(),
+      dayGained: z.number().nonnegative().optional(),
+      gainDay: z.string().optional(),
+      missingLevel: z.string().optional()
     })
     .optional()
 });
@@ -0,0 +1,204 @@
+import { z } from "zod";
+import { OverlaySettings } from "./ipc";
+
+export const defaultSettings: OverlaySettings = {
+  bounds: null,
+  displayId: null,
+  opacity: 0.92,
+  clickThrough: false,
+  captureEnabled: false,
+  captureSourceType: null,
+  captureSourceId: null,
+  captureRoi: null,
+  captureWatchdog: {
+    enabled: true,
+    stalledAfter: 8
+  },
+  uiMode: "gameplay",
+  numberLocale: "auto",
+  language: "auto",
+  serverClock: {
+    utcOffsetMinutes: 0,
+    ntpEnabled: false,
+    ntpServer: "pool.ntp.org"
+  },
+  pingMonitor: {
+    enabled: false,
+    host: "",
+    port: 443,
+    intervalSeconds: 5
+  },
+  httpApi: {
+    enabled: false,
+    host: "127.0.0.1",
+    port: 4319,
+    token: "",
+    allowedOrigins: []
+  },
+  logTail: {
+    enabled: false,
+    path: ""
+  },
+  doNotDisturb: {
+    enabled: false,
+    until: null
+  },
+  quietHours: {
+    enabled: false,
+    windows: []
+  },
+  overlayRefresh: {
+    timersMs: 1000,
+    countersMs: 1000,
+    pingMs: 2000,
+    statusMs: 5000,
+    captureMs: 15000
+  },
+  retention: {
+    enabled: true,
+    rawDays: 30,
+    rollupMinutes: 5
+  },
+  spectator: {
+    enabled: false,
+    url: "",
+    token: "",
+    pollMs: 2000
+  },
+  llm: {
+    enabled: false,
+    provider: "ollama",
+    baseUrl: "http://127.0.0.1:11434/v1",
+    model: "llama3.2:1b",
+    apiKey: ""
+  }
+};
+
+const rectSchema = z.object({
+  x: z.number(),
+  y: z.number(),
+  width: z.number().positive(),
+  height: z.number().positive()
+});
+
+const portSchema = z.number().int().min(1).max(65535);
+
+const quietHoursWindowSchema = z.object({
+  days: z.array(z.number().int().min(0).max(6)),
+  start: z.string(),
+  end: z.string()
+});
+
+const { captureWatchdog, serverClock, pingMonitor, httpApi, logTail } = defaultSettings;
+const { doNotDisturb, quietHours, overlayRefresh, retention, spectator, llm } = defaultSettings;
+
+// Every key falls back to its own default, so one bad or missing value (for
+// example from an older settings.json) does not reset the rest of the file.
+// Nested objects fall back per field, and to their whole default when they
+// are not an object at all.
+export const settingsSchema = z.object({
+  bounds: rectSchema.nullable().catch(defaultSettings.bounds),
+  displayId: z.number().nullable().catch(defaultSettings.displayId),
+  opacity: z.number().min(0).max(1).catch(defaultSettings.opacity),
+  clickThrough: z.boolean().catch(defaultSettings.clickThrough),
+  captureEnabled: z.boolean().catch(defaultSettings.captureEnabled),
+  captureSourceType: z
+    .enum(["display", "window"])
+    .nullable()
+    .catch(defaultSettings.captureSourceType),
+  captureSourceId: z.string().nullable().catch(defaultSettings.captureSourceId),
+  captureRoi: rectSchema.nullable().catch(defaultSettings.captureRoi),
+  captureWatchdog: z
+    .object({
+      enabled: z.boolean().catch(captureWatchdog.enabled),
+      stalledAfter: z.number().positive().catch(captureWatchdog.stalledAfter)
+    })
+    .catch(captureWatchdog),
+  uiMode: z.enum(["gameplay", "compose", "inspect"]).catch(defaultSettings.uiMode),
+  numberLocale: z
+    .enum(["auto", "dot-decimal", "comma-decimal"])
+    .catch(defaultSettings.numberLocale),
+  language: z.enum(["auto", "en", "es", "de"]).catch(defaultSettings.language),
+  serverClock: z
+    .object({
+      utcOffsetMinutes: z.number().int().catch(serverClock.utcOffsetMinutes),
+      ntpEnabled: z.boolean().catch(serverClock.ntpEnabled),
+      ntpServer: z.string().catch(serverClock.ntpServer)
+    })
+    .catch(serverClock),
+  pingMonitor: z
+    .object({
+      enabled: z.boolean().catch(pingMonitor.enabled),
+      host: z.string().catch(pingMonitor.host),
+      port: portSchema.catch(pingMonitor.port),
+      intervalSeconds: z.number().positive().catch(pingMonitor.intervalSeconds)
+    })
+    .catch(pingMonitor),
+  httpApi: z
+    .object({
+      enabled: z.boolean().catch(httpApi.enabled),
+      host: z.string().catch(httpApi.host),
+      port: portSchema.catch(httpApi.port),
+      token: z.string().catch(httpApi.token),
+      allowedOrigins: z.array(z.string()).catch(httpApi.allowedOrigins)
+    })
+    .catch(httpApi),
+  logTail: z
+    .object({
+      enabled: z.boolean().catch(logTail.enabled),
+      path: z.string().catch(logTail.path)
+    })
+    .catch(logTail),
+  doNotDisturb: z
+    .object({
+      enabled: z.boolean().catch(doNotDisturb.enabled),
+      until: z.number().nullable().catch(doNotDisturb.until)
+    })
+    .catch(doNotDisturb),
+  quietHours: z
+    .object({
+      enabled: z.boolean().catch(quietHours.enabled),
+      windows: z.array(quietHoursWindowSchema).catch(quietHours.windows)
+    })
+    .catch(quietHours),
+  overlayRefresh: z
+    .object({
+      timersMs: z.number().int().positive().catch(overlayRefresh.timersMs),
+      countersMs: z.number().int().positive().catch(overlayRefresh.countersMs),
+      pingMs: z.number().int().positive().catch(overlayRefresh.pingMs),
+      statusMs: z.number().int().positive().catch(overlayRefresh.statusMs),
+      captureMs: z.number().int().positive().catch(overlayRefresh.captureMs)
+    })
+    .catch(overlayRefresh),
+  retention: z
+    .object({
+      enabled: z.boolean().catch(retention.enabled),
+      rawDays: z.number().int().positive().catch(retention.rawDays),
+      rollupMinutes: z.number().int().positive().catch(retention.rollupMinutes)
+    })
+    .catch(retention),
+  spectator: z
+    .object({
+      enabled: z.boolean().catch(spectator.enabled),
+      url: z.string().catch(spectator.url),
+      token: z.string().catch(spectator.token),
+      pollMs: z.number().int().positive().catch(spectator.pollMs)
+    })
+    .catch(spectator),
+  llm: z
+    .object({
+      enabled: z.boolean().catch(llm.enabled),
+      provider: z
+        .enum(["openai", "groq", "openrouter", "mistral", "ollama", "lmstudio", "custom"])
+        .catch(llm.provider),
+      baseUrl: z.string().catch(llm.baseUrl),
+      model: z.string().catch(llm.model),
+      apiKey: z.string().optional().catch(llm.apiKey)
+    })
+    .catch(llm)
+});
+
+export const parseSettings = (data: unknown): OverlaySettings => {
+  const result = settingsSchema.safeParse(data);
+  return result.success ? result.data : defaultSettings;
+};
@@ -0,0 +1,28 @@
+import { z } from "zod";
+
+export const timerColorSchema = z
+  .string()
+  .regex(/^#(?:[0-9a-f]{3}|[0-9a-f]{6})$/i, "Timer color must be a hex value like #ff8800");
+
+export const timerRecordSchema = z.object({
+  id: z.string().min(1),
+  label: z.string().min(1),
+  endsAt: z.number().nonnegative(),
+  createdAt: z.number().nonnegative(),
+  category: z.string().min(1).optional(),
+  color: timerColorSchema.optional(),
+  repeatSeconds: z.number().int().positive().optional()
+});
+
+export const timerListOptionsSchema = z.object({
+  sort: z.enum(["soonest", "created", "label"]),
+  groupByCategory: z.boolean()
+});
+
+export const timersStoreSchema = z.object({
+  version: z.literal("1.0"),
+  timers: z.array(timerRecordSchema),
+  listOptions: timerListOptionsSchema
+});
+
+export type TimersStoreSchema = z.infer<typeof timersStoreSchema>;
@@ -0,0 +1,44 @@
+import { RuleState, ValueSmoothing } from "./ipc";
+
+const DEFAULT_CONFIRM_TICKS = 3;
+
+export type SmoothingResult = {
+  accepted: boolean;
+  pendingValue?: number;
+  pendingCount?: number;
+};
+
+const isSuspicious = (value: number, lastValue: number, smoothing: ValueSmoothing) => {
+  if (smoothing.rejectRegressions && value < lastValue) {
+    return true;
+  }
+  const ratio = smoothing.maxJumpRatio;
+  if (!ratio) {
+    return false;
+  }
+  const current = Math.abs(value);
+  const previous = Math.abs(lastValue);
+  if (current === 0 || previous === 0) {
+    return current !== previous;
+  }
+  return current / previous > ratio || previous / current > ratio;
+};
+
+// OCR flicker produces one-tick outliers (47.3 -> 4.73 -> 47.4). A suspicious
+// reading is held back until the same value repeats `confirmTicks` times in a
+// row, so genuine drops such as a level-up reset still get through.
+export const smoothValue = (
+  value: number,
+  state: RuleState | undefined,
+  smoothing: ValueSmoothing | undefined
+): SmoothingResult => {
+  const lastValue = state?.lastValue;
+  if (!smoothing || lastValue === undefined || !isSuspicious(value, lastValue, smoothing)) {
+    return { accepted: true };
+  }
+  const pendingCount = state?.pendingValue === value ? (state.pendingCount ?? 0) + 1 : 1;
+  if (pendingCount >= (smoothing.confirmTicks ?? DEFAULT_CONFIRM_TICKS)) {
+    return { accepted: true };
+  }
+  return { accepted: false, pendingValue: value, pendingCount };
+};
@@ -0,0 +1,18 @@
+import { z } from "zod";
+
+export const widgetVisibilityConditionSchema = z.discriminatedUnion("when", [
+  z.object({ when: z.literal("captureEnabled") }),
+  z.object({ when: z.literal("interactive") }),
+  z.object({
+    when: z.literal("timerRemainingBelow"),
+    timerId: z.string().min(1),
+    seconds: z.number().positive()
+  })
+]);
+
+export const widgetVisibilityRuleSchema = z.object({
+  match: z.enum(["all", "any"]),
+  conditions: z.array(widgetVisibilityConditionSchema).min(1)
+});
+
+export type WidgetVisibilityRuleSchema = z.infer<typeof widgetVisibilityRuleSchema>;
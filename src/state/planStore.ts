@@ -141,12 +141,14 @@ export const widgetSpecToOverlayPlan = (spec: WidgetSpec): OverlayPlan => {
         "Timer";
       const runningValue = record.running;
       const running = typeof runningValue === "boolean" ? runningValue : false;
+      const startedAtValue = record.startedAt;
       return {
         id: widget.id,
         type: "timer",
         title,
         seconds: Number.isFinite(seconds) ? seconds : 0,
-        running
+        running,
+        ...(running && typeof startedAtValue === "number" ? { startedAt: startedAtValue } : {})
       };
     }
     if (widget.type === "panel") {
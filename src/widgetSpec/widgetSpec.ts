@@ -34,6 +34,14 @@ export type WidgetSpec = {
 
 export type Widget = WidgetSpecWidget;
 
+export const flattenWidgets = (widgets: WidgetSpecWidget[]): WidgetSpecWidget[] =>
+  widgets.flatMap((widget) => {
+    const children = widget.children;
+    return Array.isArray(children)
+      ? [widget, ...flattenWidgets(children as WidgetSpecWidget[])]
+      : [widget];
+  });
+
 const requiredFieldSchema = z
   .object({
     key: z.string().min(1)